@@ -0,0 +1,98 @@
+//! Hand positions of an analog clock. Angles are in degrees clockwise from
+//! 12 and hands sweep, so at 9:30 the hour hand is halfway between 9 and 10.
+
+use crate::Clock;
+
+/// How fast the minute hand gains on the hour hand, in degrees per second:
+/// 6 degrees a minute minus half a degree a minute.
+const RELATIVE_DEGREES_PER_SECOND: f64 = 11.0 / 120.0;
+
+impl Clock {
+    fn exact_seconds(&self) -> f64 {
+        self.seconds as f64 + self.nanos as f64 / 1e9
+    }
+
+    /// `0.0..360.0`, a full turn every 12 hours.
+    pub fn hour_hand_angle(&self) -> f64 {
+        let minutes = self.minutes as f64 + self.exact_seconds() / 60.0;
+        ((self.hours % 12) as f64 + minutes / 60.0) * 30.0
+    }
+
+    /// `0.0..360.0`, a full turn every hour.
+    pub fn minute_hand_angle(&self) -> f64 {
+        (self.minutes as f64 + self.exact_seconds() / 60.0) * 6.0
+    }
+
+    /// `0.0..360.0`, a full turn every minute.
+    pub fn second_hand_angle(&self) -> f64 {
+        self.exact_seconds() * 6.0
+    }
+
+    /// The smaller angle between the hour and minute hand, `0.0..=180.0`.
+    pub fn hands_angle(&self) -> f64 {
+        let angle = (self.minute_hand_angle() - self.hour_hand_angle()).rem_euclid(360.0);
+        angle.min(360.0 - angle)
+    }
+
+    /// All times of day at which the hour and minute hand are `angle`
+    /// degrees apart, rounded to the second and sorted. Angles outside of
+    /// `0.0..=180.0` are measured the other way around, so `270.0` is the
+    /// same as `90.0`. Most angles occur 44 times a day, `0.0` and `180.0`
+    /// only 22 times, and a non-finite angle never.
+    pub fn with_hands_angle(angle: f64) -> Vec<Clock> {
+        if !angle.is_finite() {
+            return Vec::new();
+        }
+        let angle = angle.rem_euclid(360.0);
+        let day = 86_400.0;
+        let mut clocks: Vec<Clock> = [angle, 360.0 - angle]
+            .into_iter()
+            .flat_map(|base| {
+                (0..)
+                    .map(move |turn| (base + 360.0 * turn as f64) / RELATIVE_DEGREES_PER_SECOND)
+                    .take_while(move |&seconds| seconds < day)
+            })
+            .map(|seconds| Clock::with_seconds(0, 0, seconds.round() as i32))
+            .collect();
+        clocks.sort();
+        clocks.dedup();
+        clocks
+    }
+}
+
+#[test]
+fn test_hand_angles() {
+    let clock = Clock::new(3, 0);
+    assert_eq!(clock.hour_hand_angle(), 90.0);
+    assert_eq!(clock.minute_hand_angle(), 0.0);
+    assert_eq!(clock.hands_angle(), 90.0);
+    let clock = Clock::with_seconds(21, 30, 15);
+    assert_eq!(clock.hour_hand_angle(), 285.125);
+    assert_eq!(clock.minute_hand_angle(), 181.5);
+    assert_eq!(clock.second_hand_angle(), 90.0);
+    assert_eq!(Clock::new(9, 0).hands_angle(), 90.0);
+    assert_eq!(Clock::new(12, 30).hands_angle(), 165.0);
+    assert_eq!(Clock::new(18, 0).hands_angle(), 180.0);
+}
+
+#[test]
+fn test_with_hands_angle() {
+    let overlaps = Clock::with_hands_angle(0.0);
+    assert_eq!(overlaps.len(), 22);
+    assert_eq!(overlaps[0], Clock::new(0, 0));
+    assert_eq!(overlaps[1], Clock::with_seconds(1, 5, 27));
+    assert_eq!(overlaps[11], Clock::new(12, 0));
+
+    let right = Clock::with_hands_angle(90.0);
+    assert_eq!(right.len(), 44);
+    for clock in [Clock::new(3, 0), Clock::new(9, 0), Clock::new(15, 0)] {
+        assert!(right.contains(&clock), "{}", clock);
+    }
+    assert_eq!(Clock::with_hands_angle(270.0), right);
+    assert_eq!(Clock::with_hands_angle(180.0).len(), 22);
+    assert!(Clock::with_hands_angle(f64::NAN).is_empty());
+
+    for clock in Clock::with_hands_angle(42.5) {
+        assert!((clock.hands_angle() - 42.5).abs() < 0.05, "{}", clock);
+    }
+}
@@ -0,0 +1,72 @@
+// Proleptic Gregorian calendar helpers, counting days relative to 1970-01-01.
+// The algorithms are the well-known ones by Howard Hinnant.
+
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub(crate) fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 {
+        year as i64 - 1
+    } else {
+        year as i64
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+pub(crate) fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month, day)
+}
+
+/// 0 is Sunday, 6 is Saturday.
+pub(crate) fn weekday_from_days(days: i64) -> u32 {
+    (days + 4).rem_euclid(7) as u32
+}
+
+#[test]
+fn test_civil_round_trip() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    for days in (-800_000..800_000).step_by(97) {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+}
+
+#[test]
+fn test_weekday_from_days() {
+    assert_eq!(weekday_from_days(0), 4);
+    assert_eq!(weekday_from_days(days_from_civil(2024, 3, 31)), 0);
+}
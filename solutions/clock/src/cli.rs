@@ -0,0 +1,487 @@
+//! The subcommands of the `clock` binary, for use from shell scripts.
+//!
+//! ```text
+//! clock add 10:10 90            11:40
+//! clock diff 22:00 06:30        8h30m
+//! clock parse "9:30 pm"         21:30
+//! clock convert 12:00 --from Europe/Vienna --to UTC
+//! ```
+
+use std::{fmt::Write as _, io::Write};
+
+use crate::{
+    error::FieldValue,
+    expr::{self, Value},
+    Clock, ClockDuration, Date, LocalResult, ParseClockError, ParseClockErrorKind,
+    SystemTimeSource, TimeSource, TimeZone, ZonedClock,
+};
+
+const USAGE: &str = "\
+Usage: clock [--json] <command> [args]
+
+Commands:
+  add <clock> <amount>...                 add minutes or durations like 1h30m
+  diff <from> <to>                        the duration from one clock to the next
+  parse <input> [--format 12h|24h|<pattern>]
+  convert <clock> --from <zone> --to <zone> [--date YYYY-MM-DD]
+
+Without a command, starts an interactive calculator.
+
+Exit codes: 0 success, 1 other errors, 2 usage errors, and for input that is
+not a clock 3 empty, 4 missing separator, 5 not a number, 6 out of range,
+7 missing component, 8 invalid am/pm, 9 invalid pattern, 10 invalid offset,
+11 trailing input.";
+
+/// The exit code for a clock that failed to parse, as listed in `clock --help`.
+pub fn exit_code(kind: ParseClockErrorKind) -> u8 {
+    match kind {
+        ParseClockErrorKind::Empty => 3,
+        ParseClockErrorKind::MissingSeparator => 4,
+        ParseClockErrorKind::NotANumber(_) => 5,
+        ParseClockErrorKind::OutOfRange(_) => 6,
+        ParseClockErrorKind::MissingComponent(_) => 7,
+        ParseClockErrorKind::InvalidMeridiem => 8,
+        ParseClockErrorKind::InvalidPattern => 9,
+        ParseClockErrorKind::InvalidOffset => 10,
+        ParseClockErrorKind::TrailingInput => 11,
+    }
+}
+
+enum CliError {
+    Usage(String),
+    Parse(ParseClockError),
+    Other(String),
+}
+
+impl From<ParseClockError> for CliError {
+    fn from(err: ParseClockError) -> Self {
+        CliError::Parse(err)
+    }
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(_) => 2,
+            CliError::Parse(err) => exit_code(err.kind()),
+            CliError::Other(_) => 1,
+        }
+    }
+}
+
+enum Json {
+    String(String),
+    Number(i64),
+    Bool(bool),
+    Null,
+}
+
+impl From<FieldValue> for Json {
+    fn from(value: FieldValue) -> Self {
+        match value {
+            FieldValue::String(s) => Json::String(s),
+            FieldValue::Number(n) => Json::Number(n as i64),
+            FieldValue::Null => Json::Null,
+        }
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn json_object(fields: &[(&str, Json)]) -> String {
+    let mut out = String::from("{");
+    for (idx, (name, value)) in fields.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        write_json_string(&mut out, name);
+        out.push(':');
+        match value {
+            Json::String(s) => write_json_string(&mut out, s),
+            Json::Number(n) => write!(out, "{}", n).unwrap(),
+            Json::Bool(b) => write!(out, "{}", b).unwrap(),
+            Json::Null => out.push_str("null"),
+        }
+    }
+    out.push('}');
+    out
+}
+
+/// What a command prints: a line of text, or the fields of a JSON object.
+struct Output {
+    text: String,
+    json: Vec<(&'static str, Json)>,
+}
+
+#[derive(Default)]
+struct Args {
+    positional: Vec<String>,
+    json: bool,
+    help: bool,
+    format: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    date: Option<String>,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if arg.starts_with("--") => (flag.to_string(), Some(value)),
+                _ => (arg.clone(), None),
+            };
+            let slot = match flag.as_str() {
+                "--json" => {
+                    parsed.json = true;
+                    continue;
+                }
+                "--help" | "-h" => {
+                    parsed.help = true;
+                    continue;
+                }
+                "--format" => &mut parsed.format,
+                "--from" => &mut parsed.from,
+                "--to" => &mut parsed.to,
+                "--date" => &mut parsed.date,
+                _ if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+                _ => {
+                    parsed.positional.push(arg);
+                    continue;
+                }
+            };
+            let value = match inline {
+                Some(value) => value.to_string(),
+                None => args
+                    .next()
+                    .ok_or_else(|| format!("{} needs a value", flag))?,
+            };
+            *slot = Some(value);
+        }
+        Ok(parsed)
+    }
+
+    fn expect_positional(&self, count: usize, usage: &str) -> Result<(), CliError> {
+        if self.positional.len() != count + 1 {
+            return Err(CliError::Usage(format!("usage: clock {}", usage)));
+        }
+        Ok(())
+    }
+}
+
+/// `minutes` as a plain number, or any duration expression like `1h30m`.
+fn parse_amount(amount: &str) -> Result<ClockDuration, CliError> {
+    let not_a_duration = || CliError::Other(format!("{} is not a duration", amount));
+    match expr::evaluate(amount).map_err(|_| not_a_duration())? {
+        Value::Number(minutes) => {
+            ClockDuration::checked_minutes(minutes).ok_or_else(not_a_duration)
+        }
+        Value::Duration(duration) => Ok(duration),
+        Value::Clock(_) => Err(not_a_duration()),
+    }
+}
+
+fn parse_zone(name: Option<&String>, flag: &str) -> Result<TimeZone, CliError> {
+    let name = name.ok_or_else(|| CliError::Usage(format!("convert needs {}", flag)))?;
+    name.parse()
+        .map_err(|err| CliError::Other(format!("{}: {}", name, err)))
+}
+
+fn clock_output(clock: Clock, mut json: Vec<(&'static str, Json)>) -> Output {
+    json.insert(0, ("clock", Json::String(clock.to_string())));
+    Output {
+        text: clock.to_string(),
+        json,
+    }
+}
+
+/// Converts `clock` on `date` in `from` into `to`. A clock that is skipped
+/// when clocks go forward is converted as if it had happened, i.e. like the
+/// same time one gap later, and reported as `gap`.
+fn convert(clock: Clock, from: TimeZone, to: &TimeZone, date: Date) -> Output {
+    let from_name = from.name().to_string();
+    let converted = ZonedClock::new(clock, from).to_zone_on_date(date, to);
+    let ambiguous = matches!(converted, LocalResult::Ambiguous(..));
+    let gap = matches!(converted, LocalResult::Gap(_));
+    let mut output = clock_output(
+        converted.earliest().clock(),
+        vec![
+            ("zone", Json::String(to.name().to_string())),
+            ("ambiguous", Json::Bool(ambiguous)),
+            ("gap", Json::Bool(gap)),
+        ],
+    );
+    if gap {
+        write!(
+            output.text,
+            "\n{} does not exist in {} on {}",
+            clock, from_name, date
+        )
+        .unwrap();
+    }
+    output
+}
+
+fn execute(args: &Args) -> Result<Output, CliError> {
+    let command = args.positional[0].as_str();
+    match command {
+        "add" => {
+            if args.positional.len() < 3 {
+                return Err(CliError::Usage(
+                    "usage: clock add <clock> <amount>...".to_string(),
+                ));
+            }
+            let mut clock: Clock = args.positional[1].parse()?;
+            for amount in &args.positional[2..] {
+                clock = clock + parse_amount(amount)?;
+            }
+            Ok(clock_output(clock, Vec::new()))
+        }
+        "diff" => {
+            args.expect_positional(2, "diff <from> <to>")?;
+            let from: Clock = args.positional[1].parse()?;
+            let to: Clock = args.positional[2].parse()?;
+            let duration = to - from;
+            Ok(Output {
+                text: duration.to_string(),
+                json: vec![
+                    ("duration", Json::String(duration.to_string())),
+                    ("seconds", Json::Number(duration.whole_seconds())),
+                ],
+            })
+        }
+        "parse" => {
+            args.expect_positional(1, "parse <input> [--format 12h|24h|<pattern>]")?;
+            let clock = Clock::parse_lenient(&args.positional[1])?;
+            let text = match args.format.as_deref() {
+                None | Some("24h") => clock.to_string(),
+                Some("12h") => clock.display_12h().to_string(),
+                Some(pattern) => clock.format(pattern).to_string(),
+            };
+            let mut output = clock_output(clock, vec![("formatted", Json::String(text.clone()))]);
+            output.text = text;
+            Ok(output)
+        }
+        "convert" => {
+            args.expect_positional(1, "convert <clock> --from <zone> --to <zone>")?;
+            let clock: Clock = args.positional[1].parse()?;
+            let from = parse_zone(args.from.as_ref(), "--from")?;
+            let to = parse_zone(args.to.as_ref(), "--to")?;
+            let date = match &args.date {
+                Some(date) => date
+                    .parse::<Date>()
+                    .map_err(|err| CliError::Other(format!("{}: {}", date, err)))?,
+                None => SystemTimeSource.now_in(&from).date(),
+            };
+            Ok(convert(clock, from, &to, date))
+        }
+        _ => Err(CliError::Usage(format!(
+            "unknown command {}, see clock --help",
+            command
+        ))),
+    }
+}
+
+fn error_json(err: &CliError) -> String {
+    let fields = match err {
+        CliError::Parse(err) => err
+            .fields()
+            .into_iter()
+            .map(|(name, value)| (name, Json::from(value)))
+            .collect(),
+        CliError::Usage(message) => vec![
+            ("kind", Json::String("usage".to_string())),
+            ("message", Json::String(message.clone())),
+        ],
+        CliError::Other(message) => vec![
+            ("kind", Json::String("error".to_string())),
+            ("message", Json::String(message.clone())),
+        ],
+    };
+    format!("{{\"error\":{}}}", json_object(&fields))
+}
+
+/// Runs the command in `args`, without the program name, and returns the
+/// process exit code. Results go to `stdout` and errors to `stderr`, both as
+/// JSON objects with `--json`.
+pub fn run(
+    args: impl IntoIterator<Item = String>,
+    mut stdout: impl Write,
+    mut stderr: impl Write,
+) -> u8 {
+    let args = match Args::parse(args) {
+        Ok(args) => args,
+        Err(message) => {
+            let _ = writeln!(stderr, "{}\n\n{}", message, USAGE);
+            return CliError::Usage(message).exit_code();
+        }
+    };
+    if args.help || args.positional.is_empty() {
+        let _ = writeln!(stdout, "{}", USAGE);
+        return 0;
+    }
+    let result = execute(&args);
+    let written = match &result {
+        Ok(output) if args.json => writeln!(stdout, "{}", json_object(&output.json)),
+        Ok(output) => writeln!(stdout, "{}", output.text),
+        Err(err) if args.json => writeln!(stderr, "{}", error_json(err)),
+        Err(CliError::Parse(err)) => writeln!(stderr, "{}", err),
+        Err(CliError::Usage(message)) | Err(CliError::Other(message)) => {
+            writeln!(stderr, "{}", message)
+        }
+    };
+    match (result, written) {
+        (Err(err), _) => err.exit_code(),
+        (Ok(_), Err(_)) => 1,
+        (Ok(_), Ok(())) => 0,
+    }
+}
+
+#[cfg(test)]
+fn run_args(args: &[&str]) -> (u8, String, String) {
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let code = run(
+        args.iter().map(|arg| arg.to_string()),
+        &mut stdout,
+        &mut stderr,
+    );
+    (
+        code,
+        String::from_utf8(stdout).unwrap(),
+        String::from_utf8(stderr).unwrap(),
+    )
+}
+
+#[test]
+fn test_add_and_diff() {
+    assert_eq!(run_args(&["add", "10:10", "90"]).1, "11:40\n");
+    assert_eq!(run_args(&["add", "10:10", "1h50m", "-20"]).1, "11:40\n");
+    assert_eq!(run_args(&["diff", "22:00", "06:30"]).1, "8h30m\n");
+    assert_eq!(
+        run_args(&["diff", "22:00", "06:30", "--json"]).1,
+        "{\"duration\":\"8h30m\",\"seconds\":30600}\n"
+    );
+}
+
+#[test]
+fn test_parse_and_format() {
+    assert_eq!(run_args(&["parse", "9:30 pm"]).1, "21:30\n");
+    assert_eq!(
+        run_args(&["parse", "21:30", "--format", "12h"]).1,
+        "9:30 PM\n"
+    );
+    assert_eq!(
+        run_args(&["--json", "parse", "2130", "--format=%Hh%M"]).1,
+        "{\"clock\":\"21:30\",\"formatted\":\"21h30\"}\n"
+    );
+}
+
+#[test]
+fn test_convert() {
+    let (code, stdout, _) = run_args(&[
+        "convert",
+        "12:00",
+        "--from",
+        "+02:00",
+        "--to",
+        "UTC",
+        "--date",
+        "2024-07-01",
+    ]);
+    assert_eq!((code, stdout.as_str()), (0, "10:00\n"));
+    let (code, stdout, _) = run_args(&[
+        "convert", "23:30", "--from", "-05:00", "--to", "Z", "--json",
+    ]);
+    assert_eq!(code, 0);
+    assert_eq!(
+        stdout,
+        "{\"clock\":\"04:30\",\"zone\":\"UTC\",\"ambiguous\":false,\"gap\":false}\n"
+    );
+    let (code, _, stderr) = run_args(&["convert", "12:00", "--from", "+02:00"]);
+    assert_eq!(code, 2);
+    assert_eq!(stderr, "convert needs --to\n");
+    assert_eq!(
+        run_args(&["convert", "12:00", "--from", "Mars/Base", "--to", "UTC"]).0,
+        1
+    );
+}
+
+#[test]
+fn test_convert_reports_gaps_and_overlaps() {
+    let vienna = crate::zone::vienna_like();
+    let utc = TimeZone::utc();
+    let output = convert(
+        Clock::new(2, 30),
+        vienna.clone(),
+        &utc,
+        Date::from_ymd(2024, 3, 31).unwrap(),
+    );
+    assert_eq!(
+        output.text,
+        "01:30\n02:30 does not exist in Test/Vienna on 2024-03-31"
+    );
+    assert_eq!(
+        json_object(&output.json),
+        "{\"clock\":\"01:30\",\"zone\":\"UTC\",\"ambiguous\":false,\"gap\":true}"
+    );
+    let output = convert(
+        Clock::new(2, 30),
+        vienna,
+        &utc,
+        Date::from_ymd(2024, 10, 27).unwrap(),
+    );
+    assert_eq!(output.text, "00:30");
+    assert_eq!(
+        json_object(&output.json),
+        "{\"clock\":\"00:30\",\"zone\":\"UTC\",\"ambiguous\":true,\"gap\":false}"
+    );
+}
+
+#[test]
+fn test_exit_codes_follow_parse_errors() {
+    let (code, stdout, stderr) = run_args(&["diff", "22:xx", "06:30"]);
+    assert_eq!(code, 5);
+    assert!(stdout.is_empty());
+    assert_eq!(
+        stderr,
+        format!("{}\n", "22:xx".parse::<Clock>().unwrap_err())
+    );
+    assert_eq!(run_args(&["add", "", "1"]).0, 3);
+    assert_eq!(run_args(&["add", "12", "1"]).0, 4);
+    let (code, _, stderr) = run_args(&["--json", "add", "12:xx", "1"]);
+    assert_eq!(code, 5);
+    assert!(stderr.starts_with(
+        "{\"error\":{\"kind\":\"not_a_number\",\"component\":\"minute\",\"position\":3,"
+    ));
+    let (code, _, stderr) = run_args(&["add", "10:00", "999999999999999"]);
+    assert_eq!(code, 1);
+    assert_eq!(stderr, "999999999999999 is not a duration\n");
+    assert_eq!(run_args(&["frobnicate"]).0, 2);
+    assert_eq!(run_args(&["add", "12:00", "--bogus"]).0, 2);
+    assert_eq!(run_args(&["--help"]).0, 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json_errors_match_serde() {
+    let (_, _, stderr) = run_args(&["--json", "add", "12:xx", "1"]);
+    let err = "12:xx".parse::<Clock>().unwrap_err();
+    assert_eq!(
+        stderr,
+        format!("{{\"error\":{}}}\n", serde_json::to_string(&err).unwrap())
+    );
+}
@@ -0,0 +1,473 @@
+//! Cron expressions in the classic five field form
+//! `minute hour day-of-month month day-of-week`, or with a leading seconds
+//! field. Fields take `*`, values, ranges `a-b`, steps `*/n` or `a-b/n` and
+//! comma separated lists of those. Months and weekdays may be given as
+//! `JAN`..`DEC` and `SUN`..`SAT`, and `0` and `7` are both Sunday.
+
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::{Clock, Date, DateTime};
+
+/// How many days [`CronSchedule::next_after`] looks ahead before giving up.
+/// 28 years cover every combination of leap day and weekday.
+const SEARCH_DAYS: usize = 28 * 366;
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const WEEKDAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronField {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+impl CronField {
+    fn range(&self) -> RangeInclusive<u32> {
+        match self {
+            CronField::Second | CronField::Minute => 0..=59,
+            CronField::Hour => 0..=23,
+            CronField::DayOfMonth => 1..=31,
+            CronField::Month => 1..=12,
+            CronField::DayOfWeek => 0..=7,
+        }
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        match self {
+            CronField::Month => &MONTHS,
+            CronField::DayOfWeek => &WEEKDAYS,
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for CronField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CronField::Second => "second",
+            CronField::Minute => "minute",
+            CronField::Hour => "hour",
+            CronField::DayOfMonth => "day of month",
+            CronField::Month => "month",
+            CronField::DayOfWeek => "day of week",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseCronErrorKind {
+    /// Neither 5 nor 6 fields, holds the number found.
+    FieldCount(usize),
+    NotANumber(CronField),
+    OutOfRange(CronField),
+    /// A step that is not a positive number.
+    InvalidStep(CronField),
+    /// A range like `5-1` that ends before it starts.
+    InvalidRange(CronField),
+    /// An `@` macro other than `@yearly`, `@monthly`, `@weekly`, `@daily`
+    /// or `@hourly`.
+    UnknownMacro,
+}
+
+/// Why a string is not a cron expression, and the byte offset into the
+/// input where the problem was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCronError {
+    kind: ParseCronErrorKind,
+    position: usize,
+}
+
+impl ParseCronError {
+    fn new(kind: ParseCronErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    pub fn kind(&self) -> ParseCronErrorKind {
+        self.kind
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseCronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error parse string to CronSchedule: ")?;
+        match self.kind {
+            ParseCronErrorKind::FieldCount(count) => {
+                write!(f, "expected 5 or 6 fields but found {}", count)?
+            }
+            ParseCronErrorKind::NotANumber(field) => write!(f, "{} is not a number", field)?,
+            ParseCronErrorKind::OutOfRange(field) => {
+                let range = field.range();
+                write!(
+                    f,
+                    "{} is out of range {}..={}",
+                    field,
+                    range.start(),
+                    range.end()
+                )?
+            }
+            ParseCronErrorKind::InvalidStep(field) => {
+                write!(f, "{} step must be a positive number", field)?
+            }
+            ParseCronErrorKind::InvalidRange(field) => {
+                write!(f, "{} range ends before it starts", field)?
+            }
+            ParseCronErrorKind::UnknownMacro => write!(f, "unknown macro")?,
+        }
+        write!(f, " at byte {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseCronError {}
+
+/// A parsed cron expression. Every field is a bit set of the values it
+/// matches.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CronSchedule {
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Whether the day of month and day of week fields start with `*`. If
+    /// neither does, a day matching either of them fires, as in Vixie cron.
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+/// The set bits of `mask` from `from` upwards.
+fn bits(mask: u64, from: u32) -> impl Iterator<Item = u32> {
+    (from..64).filter(move |bit| mask & (1 << bit) != 0)
+}
+
+impl CronSchedule {
+    pub fn matches_date(&self, date: Date) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day_of_month = self.days_of_month & (1 << date.day()) != 0;
+        let day_of_week = self.days_of_week & (1 << date.weekday().number_from_sunday()) != 0;
+        if self.any_day_of_month || self.any_day_of_week {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        }
+    }
+
+    pub fn matches(&self, date_time: DateTime) -> bool {
+        let clock = date_time.clock();
+        self.matches_date(date_time.date())
+            && clock.nanos() == 0
+            && self.seconds & (1 << clock.seconds()) != 0
+            && self.minutes & (1 << clock.minutes()) != 0
+            && self.hours & (1 << clock.hours()) != 0
+    }
+
+    /// The first matching time of day at or after `from` seconds since
+    /// midnight.
+    fn first_in_day(&self, from: u32) -> Option<Clock> {
+        let (from_hour, from_minute, from_second) = (from / 3600, from / 60 % 60, from % 60);
+        for hour in bits(self.hours, from_hour) {
+            let minute_from = if hour == from_hour { from_minute } else { 0 };
+            for minute in bits(self.minutes, minute_from) {
+                let second_from = if hour == from_hour && minute == from_minute {
+                    from_second
+                } else {
+                    0
+                };
+                if let Some(second) = bits(self.seconds, second_from).next() {
+                    return Some(Clock::with_seconds(
+                        hour as i32,
+                        minute as i32,
+                        second as i32,
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    /// The first fire time strictly after `clock` on `date`, or `None` if the
+    /// expression never fires, like `0 0 30 2 *`.
+    pub fn next_after(&self, date: Date, clock: Clock) -> Option<DateTime> {
+        let mut from = (clock.hours() * 3600 + clock.minutes() * 60 + clock.seconds()) as u32 + 1;
+        let mut date = date;
+        for _ in 0..SEARCH_DAYS {
+            if from < 24 * 3600 && self.matches_date(date) {
+                if let Some(clock) = self.first_in_day(from) {
+                    return Some(date.and_clock(clock));
+                }
+            }
+            date = date.succ();
+            from = 0;
+        }
+        None
+    }
+
+    /// All fire times strictly after `clock` on `date`, in order.
+    pub fn after(&self, date: Date, clock: Clock) -> CronIter<'_> {
+        CronIter {
+            schedule: self,
+            next: self.next_after(date, clock),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CronIter<'a> {
+    schedule: &'a CronSchedule,
+    next: Option<DateTime>,
+}
+
+impl Iterator for CronIter<'_> {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.schedule.next_after(current.date(), current.clock());
+        Some(current)
+    }
+}
+
+fn parse_value(s: &str, field: CronField, position: usize) -> Result<u32, ParseCronError> {
+    let value = match field
+        .names()
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(s))
+    {
+        // Months count from 1, weekdays from 0 for Sunday.
+        Some(idx) if field == CronField::Month => idx as u32 + 1,
+        Some(idx) => idx as u32,
+        None if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => s
+            .parse()
+            .map_err(|_| ParseCronError::new(ParseCronErrorKind::OutOfRange(field), position))?,
+        None => {
+            return Err(ParseCronError::new(
+                ParseCronErrorKind::NotANumber(field),
+                position,
+            ))
+        }
+    };
+    if !field.range().contains(&value) {
+        return Err(ParseCronError::new(
+            ParseCronErrorKind::OutOfRange(field),
+            position,
+        ));
+    }
+    Ok(value)
+}
+
+fn parse_field(s: &str, field: CronField, at: usize) -> Result<u64, ParseCronError> {
+    let mut mask = 0;
+    let mut item_at = at;
+    for item in s.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step_at = item_at + range.len() + 1;
+                match step.parse::<u32>() {
+                    Ok(step) if step > 0 => (range, Some(step)),
+                    _ => {
+                        return Err(ParseCronError::new(
+                            ParseCronErrorKind::InvalidStep(field),
+                            step_at,
+                        ))
+                    }
+                }
+            }
+            None => (item, None),
+        };
+        let (start, end) = if range == "*" {
+            (*field.range().start(), *field.range().end())
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, field, item_at)?,
+                parse_value(end, field, item_at + start.len() + 1)?,
+            )
+        } else {
+            let value = parse_value(range, field, item_at)?;
+            // `5/15` means from 5 to the end in steps of 15.
+            (value, step.map_or(value, |_| *field.range().end()))
+        };
+        if start > end {
+            return Err(ParseCronError::new(
+                ParseCronErrorKind::InvalidRange(field),
+                item_at,
+            ));
+        }
+        for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+            mask |= 1 << value;
+        }
+        item_at += item.len() + 1;
+    }
+    if field == CronField::DayOfWeek && mask & (1 << 7) != 0 {
+        mask = (mask | 1) & !(1 << 7);
+    }
+    Ok(mask)
+}
+
+impl FromStr for CronSchedule {
+    type Err = ParseCronError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = match s.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            macro_ if macro_.starts_with('@') => {
+                return Err(ParseCronError::new(
+                    ParseCronErrorKind::UnknownMacro,
+                    s.len() - s.trim_start().len(),
+                ))
+            }
+            _ => s,
+        };
+        let mut fields = Vec::new();
+        let mut at = 0;
+        for part in s.split(|c: char| c.is_ascii_whitespace()) {
+            if !part.is_empty() {
+                fields.push((at, part));
+            }
+            at += part.len() + 1;
+        }
+        let (seconds, rest) = match fields.len() {
+            5 => ((0, "0"), &fields[..]),
+            6 => (fields[0], &fields[1..]),
+            count => {
+                return Err(ParseCronError::new(
+                    ParseCronErrorKind::FieldCount(count),
+                    s.len(),
+                ))
+            }
+        };
+        let field = |idx: usize, field| parse_field(rest[idx].1, field, rest[idx].0);
+        Ok(CronSchedule {
+            seconds: parse_field(seconds.1, CronField::Second, seconds.0)?,
+            minutes: field(0, CronField::Minute)?,
+            hours: field(1, CronField::Hour)?,
+            days_of_month: field(2, CronField::DayOfMonth)?,
+            months: field(3, CronField::Month)?,
+            days_of_week: field(4, CronField::DayOfWeek)?,
+            any_day_of_month: rest[2].1.starts_with('*'),
+            any_day_of_week: rest[4].1.starts_with('*'),
+        })
+    }
+}
+
+#[cfg(test)]
+fn fire_times(expression: &str, from: &str, count: usize) -> Vec<String> {
+    let schedule: CronSchedule = expression.parse().unwrap();
+    let from: DateTime = from.parse().unwrap();
+    schedule
+        .after(from.date(), from.clock())
+        .take(count)
+        .map(|date_time| date_time.to_string())
+        .collect()
+}
+
+#[test]
+fn test_steps_and_ranges() {
+    // 2024-03-08 is a Friday.
+    assert_eq!(
+        fire_times("*/15 9-17 * * 1-5", "2024-03-08T17:40", 3),
+        ["2024-03-08T17:45", "2024-03-11T09:00", "2024-03-11T09:15"]
+    );
+    assert_eq!(
+        fire_times("5/20 * * * *", "2024-03-08T10:00", 4),
+        [
+            "2024-03-08T10:05",
+            "2024-03-08T10:25",
+            "2024-03-08T10:45",
+            "2024-03-08T11:05"
+        ]
+    );
+}
+
+#[test]
+fn test_names_and_lists() {
+    assert_eq!(
+        fire_times("30 7 * JAN,jul SAT,sun", "2024-06-30T08:00", 3),
+        ["2024-07-06T07:30", "2024-07-07T07:30", "2024-07-13T07:30"]
+    );
+    assert_eq!(
+        "0 0 * * 7".parse::<CronSchedule>(),
+        "0 0 * * SUN".parse::<CronSchedule>()
+    );
+    assert_eq!(
+        fire_times("@monthly", "2024-01-31T12:00", 2),
+        ["2024-02-01T00:00", "2024-03-01T00:00"]
+    );
+}
+
+#[test]
+fn test_day_of_month_or_day_of_week() {
+    // Both restricted: the 13th or any Friday.
+    assert_eq!(
+        fire_times("0 12 13 * FRI", "2024-09-10T00:00", 3),
+        ["2024-09-13T12:00", "2024-09-20T12:00", "2024-09-27T12:00"]
+    );
+    assert_eq!(
+        fire_times("0 0 29 2 *", "2024-03-01T00:00", 1),
+        ["2028-02-29T00:00"]
+    );
+    assert!(fire_times("0 0 30 2 *", "2024-01-01T00:00", 1).is_empty());
+}
+
+#[test]
+fn test_six_fields_with_seconds() {
+    let schedule: CronSchedule = "*/20 0 12 * * *".parse().unwrap();
+    let date = Date::from_ymd(2024, 5, 1).unwrap();
+    let times: Vec<Clock> = schedule
+        .after(date, Clock::with_seconds(12, 0, 20))
+        .take(2)
+        .map(|date_time| date_time.clock())
+        .collect();
+    assert_eq!(
+        times,
+        [
+            Clock::with_seconds(12, 0, 40),
+            Clock::with_seconds(12, 0, 0)
+        ]
+    );
+    assert!(schedule.matches(date.and_clock(Clock::with_seconds(12, 0, 40))));
+    assert!(!schedule.matches(date.and_clock(Clock::with_seconds(12, 0, 30))));
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = "* * * *".parse::<CronSchedule>().unwrap_err();
+    assert_eq!(err.kind(), ParseCronErrorKind::FieldCount(4));
+    let err = "0 24 * * *".parse::<CronSchedule>().unwrap_err();
+    assert_eq!(err.kind(), ParseCronErrorKind::OutOfRange(CronField::Hour));
+    assert_eq!(err.position(), 2);
+    assert_eq!(
+        err.to_string(),
+        "Error parse string to CronSchedule: hour is out of range 0..=23 at byte 2"
+    );
+    let err = "*/0 * * * *".parse::<CronSchedule>().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ParseCronErrorKind::InvalidStep(CronField::Minute)
+    );
+    assert_eq!(err.position(), 2);
+    let err = "0 0 * FOO *".parse::<CronSchedule>().unwrap_err();
+    assert_eq!(err.kind(), ParseCronErrorKind::NotANumber(CronField::Month));
+    let err = "0 0 * * 1,5-2".parse::<CronSchedule>().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ParseCronErrorKind::InvalidRange(CronField::DayOfWeek)
+    );
+    assert_eq!(err.position(), 10);
+}
@@ -0,0 +1,411 @@
+use std::{
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    civil::{
+        civil_from_days, days_from_civil, days_in_month, is_leap_year, weekday_from_days,
+        SECONDS_PER_DAY,
+    },
+    Clock, ClockDuration, LocalResult, ParseClockError, TimeZone, NANOS_PER_DAY, NANOS_PER_SECOND,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// 1 for Monday up to 7 for Sunday, as in ISO 8601.
+    pub fn number_from_monday(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// 0 for Sunday up to 6 for Saturday, as in cron and POSIX.
+    pub fn number_from_sunday(&self) -> u32 {
+        self.number_from_monday() % 7
+    }
+
+    pub fn from_number_from_sunday(number: u32) -> Weekday {
+        Self::ALL[(number as usize + 6) % 7]
+    }
+
+    pub fn succ(&self) -> Weekday {
+        Self::ALL[(*self as usize + 1) % 7]
+    }
+
+    pub fn pred(&self) -> Weekday {
+        Self::ALL[(*self as usize + 6) % 7]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A day in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// The date `days` after 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let (year, month, day) = civil_from_days(days);
+        Self { year, month, day }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn days_since_epoch(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day)
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_number_from_sunday(weekday_from_days(self.days_since_epoch()))
+    }
+
+    /// The day of the year, starting at 1 for January 1st.
+    pub fn ordinal(&self) -> u32 {
+        (self.days_since_epoch() - days_from_civil(self.year, 1, 1)) as u32 + 1
+    }
+
+    /// The ISO 8601 week-numbering year and week (1..=53).
+    pub fn iso_week(&self) -> (i32, u32) {
+        let thursday = self.add_days(4 - self.weekday().number_from_monday() as i64);
+        (thursday.year, (thursday.ordinal() - 1) / 7 + 1)
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    pub fn succ(&self) -> Self {
+        self.add_days(1)
+    }
+
+    pub fn pred(&self) -> Self {
+        self.add_days(-1)
+    }
+
+    pub fn and_clock(&self, clock: Clock) -> DateTime {
+        DateTime::new(*self, clock)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:0>4}-{:0>2}-{:0>2}", self.year, self.month, self.day)
+        } else {
+            write!(f, "{:+05}-{:0>2}-{:0>2}", self.year, self.month, self.day)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseDateError;
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error parse string to Date")
+    }
+}
+
+impl std::error::Error for ParseDateError {}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sign, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let parts: Vec<&str> = unsigned.split('-').collect();
+        let [year, month, day] = parts[..] else {
+            return Err(ParseDateError);
+        };
+        let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if year.len() < 4 || month.len() != 2 || day.len() != 2 {
+            return Err(ParseDateError);
+        }
+        if ![year, month, day].into_iter().all(all_digits) {
+            return Err(ParseDateError);
+        }
+        let year: i32 = year.parse().map_err(|_| ParseDateError)?;
+        Date::from_ymd(
+            sign * year,
+            month.parse().map_err(|_| ParseDateError)?,
+            day.parse().map_err(|_| ParseDateError)?,
+        )
+        .ok_or(ParseDateError)
+    }
+}
+
+/// A [`Date`] together with a [`Clock`]. Unlike plain clock arithmetic,
+/// overflowing the clock carries into the date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    date: Date,
+    clock: Clock,
+}
+
+impl DateTime {
+    pub fn new(date: Date, clock: Clock) -> Self {
+        Self { date, clock }
+    }
+
+    /// The date and time at `seconds` (plus `nanos`) after the Unix epoch.
+    pub fn from_unix(seconds: i64, nanos: i32) -> Self {
+        let date = Date::from_days_since_epoch(seconds.div_euclid(SECONDS_PER_DAY));
+        let clock = Clock::from_nanos_since_midnight(
+            seconds.rem_euclid(SECONDS_PER_DAY) * NANOS_PER_SECOND + nanos as i64,
+        );
+        Self { date, clock }
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// Seconds since the Unix epoch, ignoring the sub-second part.
+    pub fn unix_seconds(&self) -> i64 {
+        self.date.days_since_epoch() * SECONDS_PER_DAY
+            + self.clock.nanos_since_midnight() / NANOS_PER_SECOND
+    }
+
+    /// Interprets this as a wall time in `from` and converts it into `to`,
+    /// carrying the date along.
+    pub fn convert_zone(&self, from: &TimeZone, to: &TimeZone) -> LocalResult<DateTime> {
+        from.local_to_utc(self.unix_seconds())
+            .map(|utc| DateTime::from_unix(to.utc_to_local(utc), self.clock.nanos()))
+    }
+}
+
+impl Add<ClockDuration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: ClockDuration) -> Self::Output {
+        let nanos = self.clock.nanos_since_midnight() + rhs.as_nanos().rem_euclid(NANOS_PER_DAY);
+        let days = rhs.as_nanos().div_euclid(NANOS_PER_DAY) + nanos / NANOS_PER_DAY;
+        DateTime {
+            date: self.date.add_days(days),
+            clock: Clock::from_nanos_since_midnight(nanos),
+        }
+    }
+}
+
+impl Sub<ClockDuration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: ClockDuration) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Add<i32> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: i32) -> Self::Output {
+        self + ClockDuration::minutes(rhs as i64)
+    }
+}
+
+impl Sub<i32> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        self + ClockDuration::minutes(-(rhs as i64))
+    }
+}
+
+impl Sub for DateTime {
+    type Output = ClockDuration;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let days = self.date.days_since_epoch() - rhs.date.days_since_epoch();
+        ClockDuration::nanoseconds(
+            days * NANOS_PER_DAY + self.clock.nanos_since_midnight()
+                - rhs.clock.nanos_since_midnight(),
+        )
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.clock)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseDateTimeError {
+    Date(ParseDateError),
+    Clock(ParseClockError),
+    MissingSeparator,
+}
+
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDateTimeError::Date(err) => write!(f, "{}", err),
+            ParseDateTimeError::Clock(err) => write!(f, "{}", err),
+            ParseDateTimeError::MissingSeparator => {
+                write!(f, "Error parse string to DateTime: expected 'T' or ' '")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+/// Parses `YYYY-MM-DDTHH:MM[:SS[.fff]]`, or with a space instead of `T`.
+/// The time must be in range, as wrapping it would silently change the day.
+impl FromStr for DateTime {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, clock) = s
+            .split_once(['T', ' '])
+            .ok_or(ParseDateTimeError::MissingSeparator)?;
+        Ok(DateTime {
+            date: date.parse().map_err(ParseDateTimeError::Date)?,
+            clock: Clock::parse_strict(clock).map_err(ParseDateTimeError::Clock)?,
+        })
+    }
+}
+
+#[test]
+fn test_date_validation() {
+    assert!(Date::from_ymd(2024, 2, 29).is_some());
+    assert!(Date::from_ymd(2023, 2, 29).is_none());
+    assert!(Date::from_ymd(2024, 13, 1).is_none());
+    assert!(Date::from_ymd(2024, 4, 31).is_none());
+    assert!(Date::from_ymd(2024, 1, 0).is_none());
+}
+
+#[test]
+fn test_weekday_and_ordinal() {
+    let date = Date::from_ymd(2024, 3, 31).unwrap();
+    assert_eq!(date.weekday(), Weekday::Sunday);
+    assert_eq!(date.ordinal(), 91);
+    assert_eq!(Date::from_ymd(2023, 12, 31).unwrap().ordinal(), 365);
+    assert_eq!(Date::from_days_since_epoch(0).weekday(), Weekday::Thursday);
+}
+
+#[test]
+fn test_iso_week() {
+    let week = |y, m, d| Date::from_ymd(y, m, d).unwrap().iso_week();
+    assert_eq!(week(2021, 1, 3), (2020, 53));
+    assert_eq!(week(2021, 1, 4), (2021, 1));
+    assert_eq!(week(2024, 12, 30), (2025, 1));
+    assert_eq!(week(2026, 10, 18), (2026, 42));
+}
+
+#[test]
+fn test_date_display_and_parse() {
+    let date = Date::from_ymd(2024, 3, 5).unwrap();
+    assert_eq!(date.to_string(), "2024-03-05");
+    assert_eq!("2024-03-05".parse::<Date>().unwrap(), date);
+    assert_eq!(
+        Date::from_ymd(-44, 3, 15).unwrap().to_string(),
+        "-0044-03-15"
+    );
+    assert_eq!("-0044-03-15".parse::<Date>().unwrap().year(), -44);
+    assert!("2024-3-5".parse::<Date>().is_err());
+    assert!("2023-02-29".parse::<Date>().is_err());
+}
+
+#[test]
+fn test_clock_overflow_carries_into_date() {
+    let dt = Date::from_ymd(2023, 12, 31)
+        .unwrap()
+        .and_clock(Clock::new(23, 59))
+        + 2;
+    assert_eq!(dt.date(), Date::from_ymd(2024, 1, 1).unwrap());
+    assert_eq!(dt.clock(), Clock::new(0, 1));
+    let dt = dt - ClockDuration::hours(49);
+    assert_eq!(dt.to_string(), "2023-12-29T23:01");
+}
+
+#[test]
+fn test_date_time_difference() {
+    let start: DateTime = "2024-02-28T22:00".parse().unwrap();
+    let end: DateTime = "2024-03-01 06:30".parse().unwrap();
+    assert_eq!(
+        end - start,
+        ClockDuration::hours(32) + ClockDuration::minutes(30)
+    );
+    assert_eq!(start + (end - start), end);
+}
+
+#[test]
+fn test_date_time_rejects_out_of_range_clock() {
+    for input in ["2024-03-10T24:30", "2024-03-10T12:60", "2024-03-10 -1:00"] {
+        assert!(
+            matches!(input.parse::<DateTime>(), Err(ParseDateTimeError::Clock(_))),
+            "{}",
+            input
+        );
+    }
+    let dt: DateTime = "2024-03-10T23:59:59.5".parse().unwrap();
+    assert_eq!(dt.clock(), Clock::with_nanos(23, 59, 59, 500_000_000));
+}
+
+#[test]
+fn test_convert_zone_carries_date() {
+    let utc = TimeZone::utc();
+    let tokyo = TimeZone::parse_offset("+09:00").unwrap();
+    let dt: DateTime = "2024-12-31T20:00".parse().unwrap();
+    let converted = dt.convert_zone(&utc, &tokyo).single().unwrap();
+    assert_eq!(converted.to_string(), "2025-01-01T05:00");
+}
@@ -0,0 +1,227 @@
+//! Views of a [`Clock`] for digital displays: seven-segment digits as ASCII
+//! art or bitmasks, and a binary clock with one BCD column per digit.
+
+use std::fmt;
+
+use crate::Clock;
+
+/// Segments `a` to `g` of the digits 0 to 9 as bits 0 to 6, the common
+/// wiring of seven-segment drivers: `a` top, `b` upper right, `c` lower
+/// right, `d` bottom, `e` lower left, `f` upper left and `g` middle.
+pub const SEGMENTS: [u8; 10] = [
+    0b011_1111, 0b000_0110, 0b101_1011, 0b100_1111, 0b110_0110, 0b110_1101, 0b111_1101, 0b000_0111,
+    0b111_1111, 0b110_1111,
+];
+
+const A: u8 = 1;
+const B: u8 = 1 << 1;
+const C: u8 = 1 << 2;
+const D: u8 = 1 << 3;
+const E: u8 = 1 << 4;
+const F: u8 = 1 << 5;
+const G: u8 = 1 << 6;
+
+/// The decimal digits `HHMM`, or `HHMMSS` with seconds.
+fn digits(clock: Clock, seconds: bool) -> Vec<u8> {
+    let mut components = vec![clock.hours(), clock.minutes()];
+    if seconds {
+        components.push(clock.seconds());
+    }
+    components
+        .into_iter()
+        .flat_map(|value| [(value / 10) as u8, (value % 10) as u8])
+        .collect()
+}
+
+/// A [`Clock`] as seven-segment digits, displayed as three lines of ASCII
+/// art. Created by [`Clock::seven_segment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SevenSegment {
+    clock: Clock,
+    seconds: bool,
+}
+
+impl SevenSegment {
+    /// Adds two digits for the seconds.
+    pub fn with_seconds(self) -> Self {
+        Self {
+            seconds: true,
+            ..self
+        }
+    }
+
+    /// The [`SEGMENTS`] bitmask of each digit, left to right.
+    pub fn masks(&self) -> Vec<u8> {
+        digits(self.clock, self.seconds)
+            .into_iter()
+            .map(|digit| SEGMENTS[digit as usize])
+            .collect()
+    }
+}
+
+impl fmt::Display for SevenSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let masks = self.masks();
+        let lit = |mask: u8, segment: u8, on: char| if mask & segment != 0 { on } else { ' ' };
+        for (row, colon) in [' ', '.', '.'].into_iter().enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for (i, &mask) in masks.iter().enumerate() {
+                if i > 0 {
+                    if i % 2 == 0 {
+                        write!(f, " {} ", colon)?;
+                    } else {
+                        f.write_str(" ")?;
+                    }
+                }
+                let cells = match row {
+                    0 => [' ', lit(mask, A, '_'), ' '],
+                    1 => [lit(mask, F, '|'), lit(mask, G, '_'), lit(mask, B, '|')],
+                    _ => [lit(mask, E, '|'), lit(mask, D, '_'), lit(mask, C, '|')],
+                };
+                for cell in cells {
+                    write!(f, "{}", cell)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A [`Clock`] as a binary clock: a column per decimal digit with its bits
+/// 8, 4, 2 and 1 from top to bottom, `#` for on and `.` for off. Created
+/// by [`Clock::binary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryClock {
+    clock: Clock,
+    seconds: bool,
+}
+
+impl BinaryClock {
+    /// Adds two columns for the seconds.
+    pub fn with_seconds(self) -> Self {
+        Self {
+            seconds: true,
+            ..self
+        }
+    }
+
+    /// Hours, minutes and seconds packed as BCD, e.g. `[0x21, 0x30, 0x15]`
+    /// for 21:30:15, as a real-time clock chip stores them.
+    pub fn bcd(&self) -> [u8; 3] {
+        [
+            self.clock.hours(),
+            self.clock.minutes(),
+            self.clock.seconds(),
+        ]
+        .map(|value| (value / 10 * 16 + value % 10) as u8)
+    }
+}
+
+impl fmt::Display for BinaryClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = digits(self.clock, self.seconds);
+        for (row, bit) in [8, 4, 2, 1].into_iter().enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for (i, &digit) in digits.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(if i % 2 == 0 { "  " } else { " " })?;
+                }
+                // Tens of hours never exceed 2 and other tens never exceed 5,
+                // so those columns have no LED for the higher bits.
+                let max = match i {
+                    0 => 2,
+                    _ if i % 2 == 0 => 5,
+                    _ => 9,
+                };
+                f.write_str(match (bit > max, digit & bit != 0) {
+                    (true, _) => " ",
+                    (false, true) => "#",
+                    (false, false) => ".",
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Clock {
+    /// Seven-segment digits `HH:MM`, see [`SevenSegment::with_seconds`].
+    pub fn seven_segment(&self) -> SevenSegment {
+        SevenSegment {
+            clock: *self,
+            seconds: false,
+        }
+    }
+
+    /// A binary clock of `HH:MM`, see [`BinaryClock::with_seconds`].
+    pub fn binary(&self) -> BinaryClock {
+        BinaryClock {
+            clock: *self,
+            seconds: false,
+        }
+    }
+}
+
+#[test]
+fn test_seven_segment_ascii_art() {
+    assert_eq!(
+        Clock::new(12, 34).seven_segment().to_string(),
+        [
+            "     _     _     ",
+            "  |  _| .  _| |_|",
+            "  | |_  .  _|   |",
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        Clock::with_seconds(5, 6, 7)
+            .seven_segment()
+            .with_seconds()
+            .to_string(),
+        [
+            " _   _     _   _     _   _ ",
+            "| | |_  . | | |_  . | |   |",
+            "|_|  _| . |_| |_| . |_|   |",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_seven_segment_masks() {
+    assert_eq!(
+        Clock::new(18, 8).seven_segment().masks(),
+        [SEGMENTS[1], SEGMENTS[8], SEGMENTS[0], SEGMENTS[8]]
+    );
+    assert_eq!(SEGMENTS[8], 0x7f);
+    assert_eq!(SEGMENTS[1], B | C);
+    assert_eq!(SEGMENTS[7], A | B | C);
+    assert_eq!(
+        Clock::with_seconds(0, 0, 42)
+            .seven_segment()
+            .with_seconds()
+            .masks()
+            .len(),
+        6
+    );
+}
+
+#[test]
+fn test_binary_clock() {
+    let clock = Clock::with_seconds(21, 39, 15);
+    assert_eq!(clock.binary().bcd(), [0x21, 0x39, 0x15]);
+    assert_eq!(
+        clock.binary().with_seconds().to_string(),
+        [
+            "  .    #    .",
+            "  .  . .  . #",
+            "# .  # .  . .",
+            ". #  # #  # #",
+        ]
+        .join("\n")
+    );
+}
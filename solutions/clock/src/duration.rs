@@ -0,0 +1,356 @@
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    time::Duration,
+};
+
+use crate::{Clock, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SECOND};
+
+/// A signed span of time with nanosecond precision, as opposed to a
+/// [`Clock`], which is a point on the 24 hour circle.
+///
+/// Like [`Duration`], the constructors and operators panic when the result
+/// does not fit, in release builds too, and each has a `checked_` variant
+/// returning `None` instead. [`ClockDuration::abs`] and negation are the
+/// exception and saturate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ClockDuration {
+    nanos: i64,
+}
+
+impl ClockDuration {
+    pub const ZERO: ClockDuration = ClockDuration { nanos: 0 };
+    pub const DAY: ClockDuration = ClockDuration {
+        nanos: NANOS_PER_DAY,
+    };
+
+    /// Panics if the duration does not fit, see [`ClockDuration::checked_hours`].
+    pub const fn hours(hours: i64) -> Self {
+        expect_in_range(Self::checked_hours(hours))
+    }
+
+    /// Panics if the duration does not fit, see [`ClockDuration::checked_minutes`].
+    pub const fn minutes(minutes: i64) -> Self {
+        expect_in_range(Self::checked_minutes(minutes))
+    }
+
+    /// Panics if the duration does not fit, see [`ClockDuration::checked_seconds`].
+    pub const fn seconds(seconds: i64) -> Self {
+        expect_in_range(Self::checked_seconds(seconds))
+    }
+
+    /// Panics if the duration does not fit, see
+    /// [`ClockDuration::checked_milliseconds`].
+    pub const fn milliseconds(milliseconds: i64) -> Self {
+        expect_in_range(Self::checked_milliseconds(milliseconds))
+    }
+
+    pub const fn checked_hours(hours: i64) -> Option<Self> {
+        Self::checked_scaled(hours, NANOS_PER_HOUR)
+    }
+
+    pub const fn checked_minutes(minutes: i64) -> Option<Self> {
+        Self::checked_scaled(minutes, NANOS_PER_MINUTE)
+    }
+
+    pub const fn checked_seconds(seconds: i64) -> Option<Self> {
+        Self::checked_scaled(seconds, NANOS_PER_SECOND)
+    }
+
+    pub const fn checked_milliseconds(milliseconds: i64) -> Option<Self> {
+        Self::checked_scaled(milliseconds, 1_000_000)
+    }
+
+    const fn checked_scaled(count: i64, nanos_per_unit: i64) -> Option<Self> {
+        match count.checked_mul(nanos_per_unit) {
+            Some(nanos) => Some(Self::nanoseconds(nanos)),
+            None => None,
+        }
+    }
+
+    pub const fn nanoseconds(nanos: i64) -> Self {
+        Self { nanos }
+    }
+
+    pub fn as_nanos(&self) -> i64 {
+        self.nanos
+    }
+
+    pub fn whole_hours(&self) -> i64 {
+        self.nanos / NANOS_PER_HOUR
+    }
+
+    pub fn whole_minutes(&self) -> i64 {
+        self.nanos / NANOS_PER_MINUTE
+    }
+
+    pub fn whole_seconds(&self) -> i64 {
+        self.nanos / NANOS_PER_SECOND
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// Saturates at the largest duration, as `i64::MIN` nanoseconds has no
+    /// positive counterpart.
+    pub fn abs(self) -> Self {
+        Self::nanoseconds(self.nanos.saturating_abs())
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.nanos.checked_add(rhs.nanos).map(Self::nanoseconds)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.nanos.checked_sub(rhs.nanos).map(Self::nanoseconds)
+    }
+
+    pub fn checked_mul(self, rhs: i32) -> Option<Self> {
+        self.nanos.checked_mul(rhs as i64).map(Self::nanoseconds)
+    }
+
+    /// `None` for a zero divisor too.
+    pub fn checked_div(self, rhs: i32) -> Option<Self> {
+        self.nanos.checked_div(rhs as i64).map(Self::nanoseconds)
+    }
+}
+
+const fn expect_in_range(duration: Option<ClockDuration>) -> ClockDuration {
+    match duration {
+        Some(duration) => duration,
+        None => panic!("overflow in ClockDuration"),
+    }
+}
+
+impl Add for ClockDuration {
+    type Output = ClockDuration;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        expect_in_range(self.checked_add(rhs))
+    }
+}
+
+impl Sub for ClockDuration {
+    type Output = ClockDuration;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        expect_in_range(self.checked_sub(rhs))
+    }
+}
+
+/// Saturates like [`ClockDuration::abs`].
+impl Neg for ClockDuration {
+    type Output = ClockDuration;
+
+    fn neg(self) -> Self::Output {
+        ClockDuration::nanoseconds(self.nanos.saturating_neg())
+    }
+}
+
+impl Mul<i32> for ClockDuration {
+    type Output = ClockDuration;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        expect_in_range(self.checked_mul(rhs))
+    }
+}
+
+impl Div<i32> for ClockDuration {
+    type Output = ClockDuration;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        assert!(rhs != 0, "division of ClockDuration by zero");
+        expect_in_range(self.checked_div(rhs))
+    }
+}
+
+impl Add<ClockDuration> for Clock {
+    type Output = Clock;
+
+    fn add(self, rhs: ClockDuration) -> Self::Output {
+        Clock::from_nanos_since_midnight(
+            self.nanos_since_midnight() + rhs.nanos.rem_euclid(NANOS_PER_DAY),
+        )
+    }
+}
+
+impl Sub<ClockDuration> for Clock {
+    type Output = Clock;
+
+    fn sub(self, rhs: ClockDuration) -> Self::Output {
+        self + -ClockDuration::nanoseconds(rhs.nanos.rem_euclid(NANOS_PER_DAY))
+    }
+}
+
+/// The distance going forward from `rhs` to `self`, always in `[0, 24h)`.
+impl Sub for Clock {
+    type Output = ClockDuration;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ClockDuration::nanoseconds(
+            (self.nanos_since_midnight() - rhs.nanos_since_midnight()).rem_euclid(NANOS_PER_DAY),
+        )
+    }
+}
+
+impl fmt::Display for ClockDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nanos == 0 {
+            return write!(f, "0s");
+        }
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        let nanos = self.nanos.unsigned_abs();
+        let hours = nanos / NANOS_PER_HOUR as u64;
+        let minutes = nanos % NANOS_PER_HOUR as u64 / NANOS_PER_MINUTE as u64;
+        let seconds = nanos % NANOS_PER_MINUTE as u64 / NANOS_PER_SECOND as u64;
+        let fraction = nanos % NANOS_PER_SECOND as u64;
+        if hours != 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}m", minutes)?;
+        }
+        if fraction != 0 {
+            let fraction = format!("{:0>9}", fraction);
+            write!(f, "{}.{}s", seconds, fraction.trim_end_matches('0'))?;
+        } else if seconds != 0 {
+            write!(f, "{}s", seconds)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ClockDurationRangeError;
+
+impl fmt::Display for ClockDurationRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Duration out of range for ClockDuration")
+    }
+}
+
+impl std::error::Error for ClockDurationRangeError {}
+
+impl TryFrom<Duration> for ClockDuration {
+    type Error = ClockDurationRangeError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(value.as_nanos())
+            .map(ClockDuration::nanoseconds)
+            .map_err(|_| ClockDurationRangeError)
+    }
+}
+
+impl TryFrom<ClockDuration> for Duration {
+    type Error = ClockDurationRangeError;
+
+    fn try_from(value: ClockDuration) -> Result<Self, Self::Error> {
+        u64::try_from(value.nanos)
+            .map(Duration::from_nanos)
+            .map_err(|_| ClockDurationRangeError)
+    }
+}
+
+#[test]
+fn test_constructors_agree() {
+    assert_eq!(ClockDuration::hours(1), ClockDuration::minutes(60));
+    assert_eq!(ClockDuration::minutes(1), ClockDuration::seconds(60));
+    assert_eq!(ClockDuration::seconds(1), ClockDuration::milliseconds(1000));
+}
+
+#[test]
+fn test_duration_arithmetic() {
+    let d = ClockDuration::hours(1) + ClockDuration::minutes(30) - ClockDuration::seconds(60);
+    assert_eq!(d.whole_minutes(), 89);
+    assert_eq!(-d, ClockDuration::minutes(-89));
+    assert_eq!(d * 2, ClockDuration::minutes(178));
+    assert_eq!(d / 89, ClockDuration::minutes(1));
+}
+
+#[test]
+fn test_abs_and_neg_saturate() {
+    let min = ClockDuration::nanoseconds(i64::MIN);
+    let max = ClockDuration::nanoseconds(i64::MAX);
+    assert_eq!(min.abs(), max);
+    assert_eq!(-min, max);
+    assert_eq!(-max, ClockDuration::nanoseconds(-i64::MAX));
+    assert_eq!(ClockDuration::minutes(-5).abs(), ClockDuration::minutes(5));
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let max = ClockDuration::nanoseconds(i64::MAX);
+    assert_eq!(
+        ClockDuration::checked_hours(2),
+        Some(ClockDuration::minutes(120))
+    );
+    assert_eq!(ClockDuration::checked_seconds(i64::MAX / 1000), None);
+    assert_eq!(ClockDuration::checked_milliseconds(i64::MIN), None);
+    assert_eq!(max.checked_add(ClockDuration::nanoseconds(1)), None);
+    assert_eq!(
+        max.checked_sub(ClockDuration::nanoseconds(1)),
+        Some(ClockDuration::nanoseconds(i64::MAX - 1))
+    );
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(ClockDuration::nanoseconds(i64::MIN).checked_div(-1), None);
+    assert_eq!(max.checked_div(0), None);
+}
+
+#[test]
+#[should_panic(expected = "overflow in ClockDuration")]
+fn test_overflowing_constructor_panics() {
+    ClockDuration::minutes(i64::MAX);
+}
+
+#[test]
+fn test_clock_minus_clock_is_forward_distance() {
+    assert_eq!(
+        Clock::new(6, 30) - Clock::new(22, 0),
+        ClockDuration::hours(8) + ClockDuration::minutes(30)
+    );
+    assert_eq!(
+        Clock::new(22, 0) - Clock::new(6, 30),
+        ClockDuration::hours(15) + ClockDuration::minutes(30)
+    );
+    assert_eq!(Clock::new(6, 30) - Clock::new(6, 30), ClockDuration::ZERO);
+}
+
+#[test]
+fn test_clock_plus_duration() {
+    assert_eq!(
+        Clock::new(23, 0) + ClockDuration::minutes(90),
+        Clock::new(0, 30)
+    );
+    assert_eq!(
+        Clock::new(0, 30) - ClockDuration::hours(1),
+        Clock::new(23, 30)
+    );
+    assert_eq!(
+        Clock::new(0, 30) + ClockDuration::hours(-49),
+        Clock::new(23, 30)
+    );
+    assert_eq!(
+        Clock::new(12, 0) - ClockDuration::nanoseconds(i64::MIN),
+        Clock::new(12, 0) + ClockDuration::nanoseconds(-i64::MIN.rem_euclid(NANOS_PER_DAY))
+    );
+}
+
+#[test]
+fn test_std_duration_conversions() {
+    let d = ClockDuration::try_from(Duration::from_millis(1500)).unwrap();
+    assert_eq!(d, ClockDuration::milliseconds(1500));
+    assert_eq!(Duration::try_from(d).unwrap(), Duration::from_millis(1500));
+    assert!(Duration::try_from(-d).is_err());
+    assert!(ClockDuration::try_from(Duration::MAX).is_err());
+}
+
+#[test]
+fn test_display_duration() {
+    assert_eq!(ClockDuration::ZERO.to_string(), "0s");
+    assert_eq!(ClockDuration::minutes(-90).to_string(), "-1h30m");
+    assert_eq!(ClockDuration::milliseconds(61_250).to_string(), "1m1.25s");
+    assert_eq!(ClockDuration::hours(2).to_string(), "2h");
+}
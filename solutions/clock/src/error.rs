@@ -0,0 +1,216 @@
+use std::{
+    fmt,
+    num::{IntErrorKind, ParseIntError},
+    ops::RangeInclusive,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    Hour,
+    Minute,
+    Second,
+    Fraction,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Component::Hour => "hour",
+            Component::Minute => "minute",
+            Component::Second => "second",
+            Component::Fraction => "fraction",
+        })
+    }
+}
+
+impl Component {
+    pub(crate) fn range(&self) -> RangeInclusive<i32> {
+        match self {
+            Component::Hour => 0..=23,
+            Component::Minute | Component::Second => 0..=59,
+            Component::Fraction => 0..=999_999_999,
+        }
+    }
+}
+
+/// A component passed to [`Clock::try_new`](crate::Clock::try_new) is not
+/// on the clock face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockRangeError {
+    component: Component,
+    value: i32,
+}
+
+impl ClockRangeError {
+    pub(crate) fn new(component: Component, value: i32) -> Self {
+        Self { component, value }
+    }
+
+    pub fn component(&self) -> Component {
+        self.component
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+impl fmt::Display for ClockRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = self.component.range();
+        write!(
+            f,
+            "{} {} is out of range {}..={}",
+            self.component,
+            self.value,
+            range.start(),
+            range.end()
+        )
+    }
+}
+
+impl std::error::Error for ClockRangeError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseClockErrorKind {
+    Empty,
+    /// A separator or other literal text was expected.
+    MissingSeparator,
+    NotANumber(Component),
+    OutOfRange(Component),
+    MissingComponent(Component),
+    /// Neither `am` nor `pm`.
+    InvalidMeridiem,
+    /// The pattern contains a directive that cannot be parsed.
+    InvalidPattern,
+    /// Neither `Z` nor a `+hh:mm` style UTC offset.
+    InvalidOffset,
+    TrailingInput,
+}
+
+impl ParseClockErrorKind {
+    /// The snake_case name used in machine-readable output.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            ParseClockErrorKind::Empty => "empty",
+            ParseClockErrorKind::MissingSeparator => "missing_separator",
+            ParseClockErrorKind::NotANumber(_) => "not_a_number",
+            ParseClockErrorKind::OutOfRange(_) => "out_of_range",
+            ParseClockErrorKind::MissingComponent(_) => "missing_component",
+            ParseClockErrorKind::InvalidMeridiem => "invalid_meridiem",
+            ParseClockErrorKind::InvalidPattern => "invalid_pattern",
+            ParseClockErrorKind::InvalidOffset => "invalid_offset",
+            ParseClockErrorKind::TrailingInput => "trailing_input",
+        }
+    }
+
+    pub(crate) fn component(&self) -> Option<Component> {
+        match self {
+            ParseClockErrorKind::NotANumber(component)
+            | ParseClockErrorKind::OutOfRange(component)
+            | ParseClockErrorKind::MissingComponent(component) => Some(*component),
+            _ => None,
+        }
+    }
+}
+
+/// Why a string could not be parsed into a [`Clock`](crate::Clock), and the
+/// byte offset into the input where the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseClockError {
+    kind: ParseClockErrorKind,
+    position: usize,
+    source: Option<ParseIntError>,
+}
+
+impl ParseClockError {
+    pub(crate) fn new(kind: ParseClockErrorKind, position: usize) -> Self {
+        Self {
+            kind,
+            position,
+            source: None,
+        }
+    }
+
+    /// Maps a failed integer parse of `component` to `NotANumber`, or to
+    /// `OutOfRange` if the digits were fine but did not fit.
+    pub(crate) fn from_int(component: Component, position: usize, err: ParseIntError) -> Self {
+        let kind = match err.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                ParseClockErrorKind::OutOfRange(component)
+            }
+            _ => ParseClockErrorKind::NotANumber(component),
+        };
+        Self {
+            kind,
+            position,
+            source: Some(err),
+        }
+    }
+
+    pub fn kind(&self) -> ParseClockErrorKind {
+        self.kind
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn offset(mut self, offset: usize) -> Self {
+        self.position += offset;
+        self
+    }
+
+    /// The fields of the machine-readable form in order, shared by the serde
+    /// impl and `clock --json` so that both write the same object.
+    pub(crate) fn fields(&self) -> [(&'static str, FieldValue); 4] {
+        [
+            ("kind", FieldValue::String(self.kind.name().to_string())),
+            (
+                "component",
+                self.kind.component().map_or(FieldValue::Null, |component| {
+                    FieldValue::String(component.to_string())
+                }),
+            ),
+            ("position", FieldValue::Number(self.position)),
+            ("message", FieldValue::String(self.to_string())),
+        ]
+    }
+}
+
+/// A value in [`ParseClockError::fields`].
+pub(crate) enum FieldValue {
+    String(String),
+    Number(usize),
+    Null,
+}
+
+impl fmt::Display for ParseClockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error parse string to Clock: ")?;
+        match self.kind {
+            ParseClockErrorKind::Empty => write!(f, "empty input")?,
+            ParseClockErrorKind::MissingSeparator => write!(f, "missing separator")?,
+            ParseClockErrorKind::NotANumber(component) => {
+                write!(f, "{} is not a number", component)?
+            }
+            ParseClockErrorKind::OutOfRange(component) => {
+                write!(f, "{} is out of range", component)?
+            }
+            ParseClockErrorKind::MissingComponent(component) => write!(f, "missing {}", component)?,
+            ParseClockErrorKind::InvalidMeridiem => write!(f, "expected am or pm")?,
+            ParseClockErrorKind::InvalidPattern => write!(f, "unsupported pattern directive")?,
+            ParseClockErrorKind::InvalidOffset => write!(f, "invalid UTC offset")?,
+            ParseClockErrorKind::TrailingInput => write!(f, "unexpected trailing input")?,
+        }
+        write!(f, " at byte {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseClockError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|err| err as &(dyn std::error::Error + 'static))
+    }
+}
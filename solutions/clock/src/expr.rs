@@ -0,0 +1,710 @@
+//! A small calculator language over clocks and durations, e.g.
+//! `10:10 + 1:50 + 240m - 1h` or `diff(22:00, 06:30) / 2`.
+//!
+//! Values are clocks (`10:10`, `10:10:30.5`), durations (`90m`, `1h30m`,
+//! with the units `h`, `m`/`min`, `s` and `ms`) and plain integers, which
+//! count minutes when mixed with the other two, like `Clock + i32` does.
+//! A clock on the right of `+` or `-` is taken as the time since midnight,
+//! so `10:10 + 1:50` is `12:00`. `diff(a, b)` is the forward duration from
+//! `a` to `b`. Names are looked up in the variables passed to the evaluator.
+
+use std::{collections::HashMap, fmt, ops::Range, str::FromStr};
+
+use crate::{Clock, ClockDuration, ParseClockError, NANOS_PER_MINUTE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Value {
+    Clock(Clock),
+    Duration(ClockDuration),
+    Number(i64),
+}
+
+impl Value {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Clock(_) => "clock",
+            Value::Duration(_) => "duration",
+            Value::Number(_) => "number",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Clock(clock) => fmt::Display::fmt(clock, f),
+            Value::Duration(duration) => fmt::Display::fmt(duration, f),
+            Value::Number(number) => fmt::Display::fmt(number, f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprErrorKind {
+    UnexpectedChar(char),
+    /// A token that does not fit the grammar where it appears.
+    UnexpectedToken,
+    UnexpectedEnd,
+    InvalidClock(ParseClockError),
+    InvalidNumber,
+    UnknownUnit,
+    UnknownVariable(String),
+    UnknownFunction(String),
+    WrongArgumentCount {
+        expected: usize,
+        found: usize,
+    },
+    /// The operator is not defined for these operand types.
+    TypeMismatch {
+        operator: &'static str,
+        left: &'static str,
+        right: &'static str,
+    },
+    DivisionByZero,
+    Overflow,
+    /// More than [`MAX_DEPTH`] nested parentheses, calls or negations.
+    TooDeep,
+}
+
+/// An error while parsing or evaluating an expression, with the byte range
+/// of the input it is about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    kind: ExprErrorKind,
+    span: Range<usize>,
+}
+
+impl ExprError {
+    fn new(kind: ExprErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    pub fn kind(&self) -> &ExprErrorKind {
+        &self.kind
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error evaluate expression: ")?;
+        match &self.kind {
+            ExprErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}", c)?,
+            ExprErrorKind::UnexpectedToken => write!(f, "unexpected token")?,
+            ExprErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ExprErrorKind::InvalidClock(err) => write!(f, "invalid clock ({})", err)?,
+            ExprErrorKind::InvalidNumber => write!(f, "number is too large")?,
+            ExprErrorKind::UnknownUnit => write!(f, "unknown unit, expected h, m, s or ms")?,
+            ExprErrorKind::UnknownVariable(name) => write!(f, "unknown variable {}", name)?,
+            ExprErrorKind::UnknownFunction(name) => write!(f, "unknown function {}", name)?,
+            ExprErrorKind::WrongArgumentCount { expected, found } => {
+                write!(f, "expected {} arguments but found {}", expected, found)?
+            }
+            ExprErrorKind::TypeMismatch {
+                operator,
+                left,
+                right,
+            } => write!(f, "cannot apply {} to {} and {}", operator, left, right)?,
+            ExprErrorKind::DivisionByZero => write!(f, "division by zero")?,
+            ExprErrorKind::Overflow => write!(f, "result is out of range")?,
+            ExprErrorKind::TooDeep => write!(f, "expression is nested too deeply")?,
+        }
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ExprError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ExprErrorKind::InvalidClock(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Clock(Clock),
+    Duration(ClockDuration),
+    Number(i64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    Comma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    span: Range<usize>,
+}
+
+fn unit_nanos(unit: &str) -> Option<i64> {
+    Some(match unit {
+        "h" => ClockDuration::hours(1).as_nanos(),
+        "m" | "min" => ClockDuration::minutes(1).as_nanos(),
+        "s" => ClockDuration::seconds(1).as_nanos(),
+        "ms" => ClockDuration::milliseconds(1).as_nanos(),
+        _ => return None,
+    })
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ExprError> {
+    let bytes = input.as_bytes();
+    let take_while = |mut at: usize, f: fn(u8) -> bool| {
+        while at < bytes.len() && f(bytes[at]) {
+            at += 1;
+        }
+        at
+    };
+    let mut tokens = Vec::new();
+    let mut at = 0;
+    while at < bytes.len() {
+        let start = at;
+        let kind = match bytes[at] {
+            b if b.is_ascii_whitespace() => {
+                at += 1;
+                continue;
+            }
+            b'0'..=b'9' => {
+                at = take_while(at, |b| b.is_ascii_digit());
+                if bytes.get(at) == Some(&b':') {
+                    at = take_while(at, |b| b.is_ascii_digit() || b == b':' || b == b'.');
+                    let clock = Clock::parse_strict(&input[start..at]).map_err(|err| {
+                        let position = start + err.position();
+                        ExprError::new(ExprErrorKind::InvalidClock(err.offset(start)), position..at)
+                    })?;
+                    TokenKind::Clock(clock)
+                } else if bytes.get(at).is_some_and(u8::is_ascii_alphabetic) {
+                    // A duration like `1h30m`, a number and a unit at a time.
+                    let mut nanos: i64 = 0;
+                    let mut number_start = start;
+                    loop {
+                        let number_end = at;
+                        at = take_while(at, |b| b.is_ascii_alphabetic());
+                        let per_unit = unit_nanos(&input[number_end..at]).ok_or_else(|| {
+                            ExprError::new(ExprErrorKind::UnknownUnit, number_end..at)
+                        })?;
+                        nanos = input[number_start..number_end]
+                            .parse::<i64>()
+                            .ok()
+                            .and_then(|number| number.checked_mul(per_unit))
+                            .and_then(|part| part.checked_add(nanos))
+                            .ok_or_else(|| {
+                                ExprError::new(ExprErrorKind::InvalidNumber, number_start..at)
+                            })?;
+                        if !bytes.get(at).is_some_and(u8::is_ascii_digit) {
+                            break;
+                        }
+                        number_start = at;
+                        at = take_while(at, |b| b.is_ascii_digit());
+                    }
+                    TokenKind::Duration(ClockDuration::nanoseconds(nanos))
+                } else {
+                    let number = input[start..at]
+                        .parse()
+                        .map_err(|_| ExprError::new(ExprErrorKind::InvalidNumber, start..at))?;
+                    TokenKind::Number(number)
+                }
+            }
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                at = take_while(at, |b| b.is_ascii_alphanumeric() || b == b'_');
+                TokenKind::Ident(input[start..at].to_string())
+            }
+            b => {
+                at += 1;
+                match b {
+                    b'+' => TokenKind::Plus,
+                    b'-' => TokenKind::Minus,
+                    b'*' => TokenKind::Star,
+                    b'/' => TokenKind::Slash,
+                    b'(' => TokenKind::LParen,
+                    b')' => TokenKind::RParen,
+                    b',' => TokenKind::Comma,
+                    _ => {
+                        let c = input[start..].chars().next().unwrap();
+                        return Err(ExprError::new(
+                            ExprErrorKind::UnexpectedChar(c),
+                            start..start + c.len_utf8(),
+                        ));
+                    }
+                }
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: start..at,
+        });
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinaryOp {
+    fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprKind {
+    Value(Value),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+/// A parsed expression, each node with the byte range of the input it came
+/// from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expr {
+    kind: ExprKind,
+    span: Range<usize>,
+    /// The number of nodes on the longest path down from this one.
+    depth: usize,
+}
+
+/// How deeply parentheses, calls, negations and operators may nest, so that
+/// parsing, evaluating and dropping an expression cannot overflow the stack.
+/// A chain like `1 + 1 + 1` nests one level per operator.
+pub const MAX_DEPTH: usize = 256;
+
+struct Parser {
+    tokens: Vec<Token>,
+    at: usize,
+    len: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.at).map(|token| &token.kind)
+    }
+
+    fn next(&mut self) -> Result<Token, ExprError> {
+        let token = self
+            .tokens
+            .get(self.at)
+            .cloned()
+            .ok_or_else(|| ExprError::new(ExprErrorKind::UnexpectedEnd, self.len..self.len))?;
+        self.at += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Token, ExprError> {
+        let token = self.next()?;
+        if token.kind != kind {
+            return Err(ExprError::new(ExprErrorKind::UnexpectedToken, token.span));
+        }
+        Ok(token)
+    }
+
+    /// A node over `kind`, unless that makes the tree deeper than
+    /// [`MAX_DEPTH`].
+    fn node(kind: ExprKind, span: Range<usize>) -> Result<Expr, ExprError> {
+        let below = match &kind {
+            ExprKind::Value(_) | ExprKind::Variable(_) => 0,
+            ExprKind::Neg(operand) => operand.depth,
+            ExprKind::Binary(_, left, right) => left.depth.max(right.depth),
+            ExprKind::Call(_, args) => args.iter().map(|arg| arg.depth).max().unwrap_or(0),
+        };
+        if below >= MAX_DEPTH {
+            return Err(ExprError::new(ExprErrorKind::TooDeep, span));
+        }
+        Ok(Expr {
+            kind,
+            span,
+            depth: below + 1,
+        })
+    }
+
+    fn binary(op: BinaryOp, left: Expr, right: Expr) -> Result<Expr, ExprError> {
+        let span = left.span.start..right.span.end;
+        Self::node(ExprKind::Binary(op, Box::new(left), Box::new(right)), span)
+    }
+
+    /// `sum := product (('+' | '-') product)*`
+    fn sum(&mut self) -> Result<Expr, ExprError> {
+        let mut left = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Some(TokenKind::Plus) => BinaryOp::Add,
+                Some(TokenKind::Minus) => BinaryOp::Sub,
+                _ => return Ok(left),
+            };
+            self.at += 1;
+            left = Self::binary(op, left, self.product()?)?;
+        }
+    }
+
+    /// `product := unary (('*' | '/') unary)*`
+    fn product(&mut self) -> Result<Expr, ExprError> {
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(TokenKind::Star) => BinaryOp::Mul,
+                Some(TokenKind::Slash) => BinaryOp::Div,
+                _ => return Ok(left),
+            };
+            self.at += 1;
+            left = Self::binary(op, left, self.unary()?)?;
+        }
+    }
+
+    /// `unary := '-' unary | primary`
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        if self.depth == MAX_DEPTH {
+            let span = self
+                .tokens
+                .get(self.at)
+                .map_or(self.len..self.len, |token| token.span.clone());
+            return Err(ExprError::new(ExprErrorKind::TooDeep, span));
+        }
+        self.depth += 1;
+        let expr = self.nested_unary();
+        self.depth -= 1;
+        expr
+    }
+
+    fn nested_unary(&mut self) -> Result<Expr, ExprError> {
+        if self.peek() == Some(&TokenKind::Minus) {
+            let minus = self.next()?;
+            let operand = self.unary()?;
+            let span = minus.span.start..operand.span.end;
+            return Self::node(ExprKind::Neg(Box::new(operand)), span);
+        }
+        self.primary()
+    }
+
+    /// `primary := literal | name | name '(' (sum (',' sum)*)? ')' | '(' sum ')'`
+    fn primary(&mut self) -> Result<Expr, ExprError> {
+        let token = self.next()?;
+        let kind = match token.kind {
+            TokenKind::Clock(clock) => ExprKind::Value(Value::Clock(clock)),
+            TokenKind::Duration(duration) => ExprKind::Value(Value::Duration(duration)),
+            TokenKind::Number(number) => ExprKind::Value(Value::Number(number)),
+            TokenKind::Ident(name) if self.peek() == Some(&TokenKind::LParen) => {
+                self.at += 1;
+                let mut args = Vec::new();
+                if self.peek() != Some(&TokenKind::RParen) {
+                    args.push(self.sum()?);
+                    while self.peek() == Some(&TokenKind::Comma) {
+                        self.at += 1;
+                        args.push(self.sum()?);
+                    }
+                }
+                let close = self.expect(TokenKind::RParen)?;
+                return Self::node(ExprKind::Call(name, args), token.span.start..close.span.end);
+            }
+            TokenKind::Ident(name) => ExprKind::Variable(name),
+            TokenKind::LParen => {
+                let inner = self.sum()?;
+                let close = self.expect(TokenKind::RParen)?;
+                return Ok(Expr {
+                    span: token.span.start..close.span.end,
+                    ..inner
+                });
+            }
+            _ => return Err(ExprError::new(ExprErrorKind::UnexpectedToken, token.span)),
+        };
+        Self::node(kind, token.span)
+    }
+}
+
+fn minutes(number: i64) -> Option<ClockDuration> {
+    number
+        .checked_mul(NANOS_PER_MINUTE)
+        .map(ClockDuration::nanoseconds)
+}
+
+fn since_midnight(clock: Clock) -> ClockDuration {
+    ClockDuration::nanoseconds(clock.nanos_since_midnight())
+}
+
+fn apply(op: BinaryOp, left: Value, right: Value) -> Result<Value, ExprErrorKind> {
+    use Value::{Clock as C, Duration as D, Number as N};
+
+    let duration = |nanos: Option<i64>| {
+        nanos
+            .map(|nanos| D(ClockDuration::nanoseconds(nanos)))
+            .ok_or(ExprErrorKind::Overflow)
+    };
+    let as_duration = |value: Value| match value {
+        C(clock) => Some(since_midnight(clock)),
+        D(duration) => Some(duration),
+        N(number) => minutes(number),
+    };
+    match (op, left, right) {
+        (BinaryOp::Add | BinaryOp::Sub, C(clock), other) => {
+            let by = as_duration(other).ok_or(ExprErrorKind::Overflow)?;
+            Ok(C(if op == BinaryOp::Add {
+                clock + by
+            } else {
+                clock - by
+            }))
+        }
+        (BinaryOp::Add, other, C(clock)) => {
+            Ok(C(clock + as_duration(other).ok_or(ExprErrorKind::Overflow)?))
+        }
+        (BinaryOp::Add, N(a), N(b)) => a.checked_add(b).map(N).ok_or(ExprErrorKind::Overflow),
+        (BinaryOp::Sub, N(a), N(b)) => a.checked_sub(b).map(N).ok_or(ExprErrorKind::Overflow),
+        (BinaryOp::Add | BinaryOp::Sub, a @ (D(_) | N(_)), b @ (D(_) | N(_))) => {
+            let a = as_duration(a).ok_or(ExprErrorKind::Overflow)?.as_nanos();
+            let b = as_duration(b).ok_or(ExprErrorKind::Overflow)?.as_nanos();
+            duration(if op == BinaryOp::Add {
+                a.checked_add(b)
+            } else {
+                a.checked_sub(b)
+            })
+        }
+        (BinaryOp::Mul, N(a), N(b)) => a.checked_mul(b).map(N).ok_or(ExprErrorKind::Overflow),
+        (BinaryOp::Mul, D(d), N(n)) | (BinaryOp::Mul, N(n), D(d)) => {
+            duration(d.as_nanos().checked_mul(n))
+        }
+        (BinaryOp::Div, _, N(0)) => Err(ExprErrorKind::DivisionByZero),
+        (BinaryOp::Div, N(a), N(b)) => a.checked_div(b).map(N).ok_or(ExprErrorKind::Overflow),
+        (BinaryOp::Div, D(d), N(n)) => duration(d.as_nanos().checked_div(n)),
+        (op, left, right) => Err(ExprErrorKind::TypeMismatch {
+            operator: op.symbol(),
+            left: left.type_name(),
+            right: right.type_name(),
+        }),
+    }
+}
+
+impl Expr {
+    pub fn parse(input: &str) -> Result<Expr, ExprError> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            at: 0,
+            len: input.len(),
+            depth: 0,
+        };
+        let expr = parser.sum()?;
+        if let Some(token) = parser.tokens.get(parser.at) {
+            return Err(ExprError::new(
+                ExprErrorKind::UnexpectedToken,
+                token.span.clone(),
+            ));
+        }
+        Ok(expr)
+    }
+
+    pub fn kind(&self) -> &ExprKind {
+        &self.kind
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    pub fn eval(&self, variables: &HashMap<String, Value>) -> Result<Value, ExprError> {
+        let error = |kind| ExprError::new(kind, self.span.clone());
+        match &self.kind {
+            ExprKind::Value(value) => Ok(*value),
+            ExprKind::Variable(name) => variables
+                .get(name)
+                .copied()
+                .ok_or_else(|| error(ExprErrorKind::UnknownVariable(name.clone()))),
+            ExprKind::Neg(operand) => match operand.eval(variables)? {
+                Value::Number(number) => number
+                    .checked_neg()
+                    .map(Value::Number)
+                    .ok_or_else(|| error(ExprErrorKind::Overflow)),
+                Value::Duration(duration) => Ok(Value::Duration(-duration)),
+                Value::Clock(_) => Err(error(ExprErrorKind::TypeMismatch {
+                    operator: "-",
+                    left: "nothing",
+                    right: "clock",
+                })),
+            },
+            ExprKind::Binary(op, left, right) => {
+                apply(*op, left.eval(variables)?, right.eval(variables)?).map_err(error)
+            }
+            ExprKind::Call(name, args) => {
+                let expected = match name.as_str() {
+                    "diff" => 2,
+                    _ => return Err(error(ExprErrorKind::UnknownFunction(name.clone()))),
+                };
+                if args.len() != expected {
+                    return Err(error(ExprErrorKind::WrongArgumentCount {
+                        expected,
+                        found: args.len(),
+                    }));
+                }
+                let clock = |arg: &Expr| match arg.eval(variables)? {
+                    Value::Clock(clock) => Ok(clock),
+                    other => Err(ExprError::new(
+                        ExprErrorKind::TypeMismatch {
+                            operator: "diff",
+                            left: "clock",
+                            right: other.type_name(),
+                        },
+                        arg.span.clone(),
+                    )),
+                };
+                Ok(Value::Duration(clock(&args[1])? - clock(&args[0])?))
+            }
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Expr::parse(s)
+    }
+}
+
+/// Parses and evaluates `input` without any variables.
+pub fn evaluate(input: &str) -> Result<Value, ExprError> {
+    Expr::parse(input)?.eval(&HashMap::new())
+}
+
+#[test]
+fn test_clock_arithmetic() {
+    assert_eq!(
+        evaluate("10:10 + 1:50 + 240m - 1h").unwrap(),
+        Value::Clock(Clock::new(15, 0))
+    );
+    assert_eq!(
+        evaluate("10:10 + 1:50 + 4 * 60").unwrap(),
+        Value::Clock(Clock::new(16, 0))
+    );
+    assert_eq!(
+        evaluate("00:30 - (1h30m + 15)").unwrap(),
+        Value::Clock(Clock::new(22, 45))
+    );
+    assert_eq!(
+        evaluate("12:00:00.5 + 500ms").unwrap().to_string(),
+        "12:00:01"
+    );
+}
+
+#[test]
+fn test_durations_and_numbers() {
+    assert_eq!(
+        evaluate("diff(22:00, 06:30)").unwrap(),
+        Value::Duration(ClockDuration::hours(8) + ClockDuration::minutes(30))
+    );
+    assert_eq!(
+        evaluate("diff(22:00, 06:30) / 2").unwrap().to_string(),
+        "4h15m"
+    );
+    assert_eq!(evaluate("-(2 * 90m) + 1").unwrap().to_string(), "-2h59m");
+    assert_eq!(evaluate("7 - 2 * 3").unwrap(), Value::Number(1));
+}
+
+#[test]
+fn test_variables() {
+    let mut variables = HashMap::new();
+    variables.insert("start".to_string(), Value::Clock(Clock::new(8, 45)));
+    let expr = Expr::parse("start + 8h").unwrap();
+    assert_eq!(expr.span(), 0..10);
+    assert_eq!(
+        expr.eval(&variables).unwrap(),
+        Value::Clock(Clock::new(16, 45))
+    );
+    let err = Expr::parse("end - 1h")
+        .unwrap()
+        .eval(&variables)
+        .unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &ExprErrorKind::UnknownVariable("end".to_string())
+    );
+    assert_eq!(err.span(), 0..3);
+}
+
+#[test]
+fn test_errors_have_spans() {
+    let err = evaluate("10:10 + 3x").unwrap_err();
+    assert_eq!(err.kind(), &ExprErrorKind::UnknownUnit);
+    assert_eq!(err.span(), 9..10);
+    let err = evaluate("10:10 + 25:00").unwrap_err();
+    assert!(matches!(err.kind(), ExprErrorKind::InvalidClock(_)));
+    assert_eq!(err.span(), 8..13);
+    let err = evaluate("1h * 10:00").unwrap_err();
+    assert_eq!(err.span(), 0..10);
+    assert_eq!(
+        err.to_string(),
+        "Error evaluate expression: cannot apply * to duration and clock at 0..10"
+    );
+    assert_eq!(
+        evaluate("(1h").unwrap_err().kind(),
+        &ExprErrorKind::UnexpectedEnd
+    );
+    assert_eq!(evaluate("1h 2h").unwrap_err().span(), 3..5);
+    assert_eq!(
+        evaluate("5 / (3 - 3)").unwrap_err().kind(),
+        &ExprErrorKind::DivisionByZero
+    );
+    assert_eq!(
+        evaluate("diff(1:00)").unwrap_err().kind(),
+        &ExprErrorKind::WrongArgumentCount {
+            expected: 2,
+            found: 1
+        }
+    );
+    assert_eq!(evaluate("1 ? 2").unwrap_err().span(), 2..3);
+}
+
+#[test]
+fn test_nesting_depth_is_limited() {
+    let nested = format!(
+        "{}1h{}",
+        "(".repeat(MAX_DEPTH - 1),
+        ")".repeat(MAX_DEPTH - 1)
+    );
+    assert_eq!(
+        evaluate(&nested).unwrap(),
+        Value::Duration(ClockDuration::hours(1))
+    );
+    assert_eq!(
+        evaluate(&"-".repeat(MAX_DEPTH - 1)).unwrap_err().kind(),
+        &ExprErrorKind::UnexpectedEnd
+    );
+
+    let err = evaluate(&("(".repeat(2000) + "1")).unwrap_err();
+    assert_eq!(err.kind(), &ExprErrorKind::TooDeep);
+    assert_eq!(err.span(), MAX_DEPTH..MAX_DEPTH + 1);
+    let err = evaluate(&("1 + ".to_string() + &"-".repeat(100_000) + "1")).unwrap_err();
+    assert_eq!(err.kind(), &ExprErrorKind::TooDeep);
+    assert_eq!(err.span(), 4 + MAX_DEPTH..5 + MAX_DEPTH);
+    assert_eq!(
+        evaluate(&("diff(".repeat(1000) + "1h")).unwrap_err().kind(),
+        &ExprErrorKind::TooDeep
+    );
+
+    let chain = vec!["1"; MAX_DEPTH].join("+");
+    assert_eq!(evaluate(&chain).unwrap(), Value::Number(MAX_DEPTH as i64));
+    let err = evaluate(&vec!["1"; 200_000].join("+")).unwrap_err();
+    assert_eq!(err.kind(), &ExprErrorKind::TooDeep);
+    assert_eq!(err.span(), 0..2 * MAX_DEPTH + 1);
+    let grouped = format!("({})*2+", chain).repeat(MAX_DEPTH) + "0";
+    assert_eq!(
+        evaluate(&grouped).unwrap_err().kind(),
+        &ExprErrorKind::TooDeep
+    );
+}
@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::Clock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pad {
+    Zero,
+    Space,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Numeric {
+    Hour,
+    Hour12,
+    Minute,
+    Second,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Item<'a> {
+    Literal(&'a str),
+    Numeric(Numeric, Pad),
+    /// `%f` with the number of digits, 9 unless given as in `%3f`.
+    Fraction(usize),
+    /// `%p` for `AM`/`PM`, `%P` for `am`/`pm`.
+    AmPm {
+        upper: bool,
+    },
+    /// A shorthand like `%R`, expanded to its own pattern.
+    Composite(&'static str),
+    /// A directive this module does not know, kept verbatim.
+    Unknown(&'a str),
+}
+
+/// Splits a strftime-like pattern into literal text and directives.
+pub(crate) struct Items<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Items<'a> {
+    pub(crate) fn new(pattern: &'a str) -> Self {
+        Self { rest: pattern }
+    }
+}
+
+impl<'a> Iterator for Items<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        if !self.rest.starts_with('%') {
+            let end = self.rest.find('%').unwrap_or(self.rest.len());
+            let (literal, rest) = self.rest.split_at(end);
+            self.rest = rest;
+            return Some(Item::Literal(literal));
+        }
+        let directive = &self.rest[1..];
+        let (pad, spec) = match directive.as_bytes().first() {
+            Some(b'-') => (Some(Pad::None), &directive[1..]),
+            Some(b'_') => (Some(Pad::Space), &directive[1..]),
+            Some(b'0') => (Some(Pad::Zero), &directive[1..]),
+            _ => (None, directive),
+        };
+        let width_len = spec.bytes().take_while(u8::is_ascii_digit).count();
+        let (width, spec) = spec.split_at(width_len);
+        let Some(c) = spec.chars().next() else {
+            let unknown = self.rest;
+            self.rest = "";
+            return Some(Item::Unknown(unknown));
+        };
+        let len = self.rest.len() - spec.len() + c.len_utf8();
+        let (raw, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        let numeric = |kind| Item::Numeric(kind, pad.unwrap_or(Pad::Zero));
+        let item = match (c, width) {
+            ('H', "") => numeric(Numeric::Hour),
+            ('k', "") => Item::Numeric(Numeric::Hour, pad.unwrap_or(Pad::Space)),
+            ('I', "") => numeric(Numeric::Hour12),
+            ('l', "") => Item::Numeric(Numeric::Hour12, pad.unwrap_or(Pad::Space)),
+            ('M', "") => numeric(Numeric::Minute),
+            ('S', "") => numeric(Numeric::Second),
+            ('f', "") if pad.is_none() => Item::Fraction(9),
+            ('f', width) if pad.is_none() => match width.parse() {
+                Ok(digits @ 1..=9) => Item::Fraction(digits),
+                _ => Item::Unknown(raw),
+            },
+            ('p', "") if pad.is_none() => Item::AmPm { upper: true },
+            ('P', "") if pad.is_none() => Item::AmPm { upper: false },
+            ('R', "") if pad.is_none() => Item::Composite("%H:%M"),
+            ('T', "") if pad.is_none() => Item::Composite("%H:%M:%S"),
+            ('r', "") if pad.is_none() => Item::Composite("%I:%M:%S %p"),
+            ('%', "") if pad.is_none() => Item::Literal("%"),
+            _ => Item::Unknown(raw),
+        };
+        Some(item)
+    }
+}
+
+pub(crate) fn hour12(hours: i32) -> i32 {
+    match hours % 12 {
+        0 => 12,
+        hours => hours,
+    }
+}
+
+/// A [`Clock`] paired with a pattern, formatted only when displayed.
+/// Created by [`Clock::format`].
+#[derive(Debug, Clone, Copy)]
+pub struct ClockFormat<'a> {
+    clock: Clock,
+    pattern: &'a str,
+}
+
+impl ClockFormat<'_> {
+    fn write_items(&self, f: &mut fmt::Formatter<'_>, pattern: &str) -> fmt::Result {
+        for item in Items::new(pattern) {
+            match item {
+                Item::Literal(text) | Item::Unknown(text) => f.write_str(text)?,
+                Item::Numeric(kind, pad) => {
+                    let value = match kind {
+                        Numeric::Hour => self.clock.hours(),
+                        Numeric::Hour12 => hour12(self.clock.hours()),
+                        Numeric::Minute => self.clock.minutes(),
+                        Numeric::Second => self.clock.seconds(),
+                    };
+                    match pad {
+                        Pad::Zero => write!(f, "{:0>2}", value)?,
+                        Pad::Space => write!(f, "{:>2}", value)?,
+                        Pad::None => write!(f, "{}", value)?,
+                    }
+                }
+                Item::Fraction(digits) => {
+                    let fraction = self.clock.nanos() / 10_i32.pow(9 - digits as u32);
+                    write!(f, "{:0>width$}", fraction, width = digits)?
+                }
+                Item::AmPm { upper } => f.write_str(match (self.clock.hours() < 12, upper) {
+                    (true, true) => "AM",
+                    (false, true) => "PM",
+                    (true, false) => "am",
+                    (false, false) => "pm",
+                })?,
+                Item::Composite(pattern) => self.write_items(f, pattern)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ClockFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_items(f, self.pattern)
+    }
+}
+
+/// A [`Clock`] on a 12 hour dial like `9:30 PM`, the same as formatting it
+/// with `{:#}`. Created by [`Clock::display_12h`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Display12h {
+    clock: Clock,
+}
+
+impl fmt::Display for Display12h {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self.clock)
+    }
+}
+
+impl Clock {
+    /// Same as formatting with `{:#}`, e.g. `9:30 PM`. Seconds and fractions
+    /// are only shown when set, as in `9:30:15.250 PM`.
+    pub fn display_12h(&self) -> Display12h {
+        Display12h { clock: *self }
+    }
+
+    /// Formats with strftime-like directives: `%H`, `%k`, `%I`, `%l`, `%M`,
+    /// `%S`, `%f` (or `%3f`, `%6f`, ...), `%p`, `%P`, `%R`, `%T`, `%r` and
+    /// `%%`. Numeric directives take a `-` (no padding), `_` (spaces) or `0`
+    /// (zeros) modifier, e.g. `%-I`. Unknown directives are written verbatim.
+    pub fn format<'a>(&self, pattern: &'a str) -> ClockFormat<'a> {
+        ClockFormat {
+            clock: *self,
+            pattern,
+        }
+    }
+}
+
+#[test]
+fn test_format_24h() {
+    let clock = Clock::with_seconds(9, 5, 7);
+    assert_eq!(clock.format("%H:%M:%S").to_string(), "09:05:07");
+    assert_eq!(clock.format("%T").to_string(), "09:05:07");
+    assert_eq!(clock.format("%R").to_string(), "09:05");
+    assert_eq!(clock.format("%kh%M").to_string(), " 9h05");
+}
+
+#[test]
+fn test_format_12h() {
+    assert_eq!(Clock::new(0, 15).format("%I:%M %p").to_string(), "12:15 AM");
+    assert_eq!(Clock::new(12, 0).format("%-I:%M%P").to_string(), "12:00pm");
+    assert_eq!(
+        Clock::new(21, 30).format("%-I.%M %p").to_string(),
+        "9.30 PM"
+    );
+    assert_eq!(Clock::new(21, 30).format("%l%P").to_string(), " 9pm");
+    assert_eq!(
+        Clock::with_seconds(23, 59, 59).format("%r").to_string(),
+        "11:59:59 PM"
+    );
+}
+
+#[test]
+fn test_format_padding_modifiers() {
+    let clock = Clock::with_seconds(7, 3, 2);
+    assert_eq!(clock.format("%-H:%-M:%-S").to_string(), "7:3:2");
+    assert_eq!(clock.format("%_H|%_M").to_string(), " 7| 3");
+    assert_eq!(clock.format("%0k").to_string(), "07");
+}
+
+#[test]
+fn test_format_fraction() {
+    let clock = Clock::with_nanos(12, 30, 15, 250_000_000);
+    assert_eq!(clock.format("%T.%3f").to_string(), "12:30:15.250");
+    assert_eq!(clock.format("%f").to_string(), "250000000");
+    assert_eq!(clock.format("%6f").to_string(), "250000");
+}
+
+#[test]
+fn test_format_literals_and_unknown_directives() {
+    let clock = Clock::new(8, 0);
+    assert_eq!(clock.format("at %H%% %Q").to_string(), "at 08% %Q");
+    assert_eq!(clock.format("%H %").to_string(), "08 %");
+    assert_eq!(clock.format("%12f|%-p").to_string(), "%12f|%-p");
+    assert_eq!(clock.format("§%H€").to_string(), "§08€");
+}
+
+#[test]
+fn test_format_in_write() {
+    use std::fmt::Write;
+    let mut out = String::new();
+    write!(out, "opens at {}", Clock::new(8, 0).format("%-I %p")).unwrap();
+    assert_eq!(out, "opens at 8 AM");
+}
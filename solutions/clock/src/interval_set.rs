@@ -0,0 +1,256 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr, Not, Sub},
+};
+
+use crate::{Clock, ClockDuration, ClockRange, NANOS_PER_DAY};
+
+/// A set of times of day, stored as sorted, disjoint and non-touching
+/// intervals in nanos since midnight. Ranges that wrap midnight are split
+/// at midnight when inserted and joined again by [`ClockIntervalSet::ranges`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ClockIntervalSet {
+    intervals: Vec<(i64, i64)>,
+}
+
+impl ClockIntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn full_day() -> Self {
+        Self {
+            intervals: vec![(0, NANOS_PER_DAY)],
+        }
+    }
+
+    fn from_sorted(intervals: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        let mut merged: Vec<(i64, i64)> = Vec::new();
+        for (start, end) in intervals {
+            if start >= end {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    pub fn insert(&mut self, range: ClockRange) {
+        *self = self.union(&Self::from(range));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn is_full_day(&self) -> bool {
+        self.intervals == [(0, NANOS_PER_DAY)]
+    }
+
+    pub fn contains(&self, clock: Clock) -> bool {
+        let nanos = clock.nanos_since_midnight();
+        let idx = self.intervals.partition_point(|&(_, end)| end <= nanos);
+        self.intervals
+            .get(idx)
+            .is_some_and(|&(start, _)| start <= nanos)
+    }
+
+    pub fn duration(&self) -> ClockDuration {
+        ClockDuration::nanoseconds(self.intervals.iter().map(|(start, end)| end - start).sum())
+    }
+
+    pub fn union(&self, other: &ClockIntervalSet) -> ClockIntervalSet {
+        let mut intervals = [&self.intervals[..], &other.intervals[..]].concat();
+        intervals.sort_unstable();
+        Self::from_sorted(intervals)
+    }
+
+    pub fn intersection(&self, other: &ClockIntervalSet) -> ClockIntervalSet {
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = Vec::new();
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a_start, a_end) = self.intervals[i];
+            let (b_start, b_end) = other.intervals[j];
+            intervals.push((a_start.max(b_start), a_end.min(b_end)));
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self::from_sorted(intervals)
+    }
+
+    /// Everything in `self` that is not in `other`.
+    pub fn difference(&self, other: &ClockIntervalSet) -> ClockIntervalSet {
+        self.intersection(&other.complement())
+    }
+
+    /// Everything on the 24 hour circle that is not in `self`.
+    pub fn complement(&self) -> ClockIntervalSet {
+        let bounds = std::iter::once(0)
+            .chain(self.intervals.iter().flat_map(|&(start, end)| [start, end]))
+            .chain(std::iter::once(NANOS_PER_DAY))
+            .collect::<Vec<_>>();
+        Self::from_sorted(bounds.chunks(2).map(|gap| (gap[0], gap[1])))
+    }
+
+    /// The set as ranges in order of their start, where a piece that ends at
+    /// midnight and one that starts at midnight come back as one wrapping
+    /// range, which is then listed last.
+    pub fn ranges(&self) -> Vec<ClockRange> {
+        let to_range = |(start, end): (i64, i64)| {
+            ClockRange::from_start(
+                Clock::from_nanos_since_midnight(start),
+                ClockDuration::nanoseconds(end - start),
+            )
+        };
+        let mut intervals = self.intervals.clone();
+        let wraps = intervals.len() > 1
+            && intervals[0].0 == 0
+            && intervals[intervals.len() - 1].1 == NANOS_PER_DAY;
+        if wraps {
+            let (first_start, first_end) = intervals.remove(0);
+            let last = intervals.last_mut().unwrap();
+            last.1 += first_end - first_start;
+        }
+        intervals.into_iter().map(to_range).collect()
+    }
+}
+
+impl From<ClockRange> for ClockIntervalSet {
+    fn from(range: ClockRange) -> Self {
+        let start = range.start().nanos_since_midnight();
+        let end = start + range.duration().as_nanos();
+        if end > NANOS_PER_DAY {
+            Self::from_sorted([(0, end - NANOS_PER_DAY), (start, NANOS_PER_DAY)])
+        } else {
+            Self::from_sorted([(start, end)])
+        }
+    }
+}
+
+impl FromIterator<ClockRange> for ClockIntervalSet {
+    fn from_iter<T: IntoIterator<Item = ClockRange>>(iter: T) -> Self {
+        let mut set = ClockIntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<ClockRange> for ClockIntervalSet {
+    fn extend<T: IntoIterator<Item = ClockRange>>(&mut self, iter: T) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        for range in iter {
+            intervals.extend(ClockIntervalSet::from(range).intervals);
+        }
+        intervals.sort_unstable();
+        *self = Self::from_sorted(intervals);
+    }
+}
+
+impl BitOr for &ClockIntervalSet {
+    type Output = ClockIntervalSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for &ClockIntervalSet {
+    type Output = ClockIntervalSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for &ClockIntervalSet {
+    type Output = ClockIntervalSet;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl Not for &ClockIntervalSet {
+    type Output = ClockIntervalSet;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl fmt::Display for ClockIntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, range) in self.ranges().iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", range)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn set(ranges: &[&str]) -> ClockIntervalSet {
+    ranges
+        .iter()
+        .map(|range| range.parse::<ClockRange>().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_merges_overlapping_and_touching_ranges() {
+    let hours = set(&["08:00-12:00", "11:00-13:00", "13:00-14:00", "16:00-18:00"]);
+    assert_eq!(hours.to_string(), "08:00-14:00, 16:00-18:00");
+    assert_eq!(hours.duration(), ClockDuration::hours(8));
+}
+
+#[test]
+fn test_wrapping_ranges() {
+    let mut night = set(&["22:00-02:00"]);
+    assert!(night.contains(Clock::new(23, 0)));
+    assert!(night.contains(Clock::new(1, 0)));
+    assert!(!night.contains(Clock::new(2, 0)));
+    night.insert("01:00-03:00".parse().unwrap());
+    assert_eq!(night.to_string(), "22:00-03:00");
+    assert_eq!(night.ranges().len(), 1);
+    assert_eq!(night.duration(), ClockDuration::hours(5));
+}
+
+#[test]
+fn test_opening_hours_minus_breaks() {
+    let open = set(&["08:00-18:00"]);
+    let lunch = set(&["12:00-13:00"]);
+    let maintenance = set(&["17:30-19:00", "07:00-08:15"]);
+    let available = &(&open - &lunch) - &maintenance;
+    assert_eq!(available.to_string(), "08:15-12:00, 13:00-17:30");
+    assert_eq!(
+        available.duration(),
+        ClockDuration::hours(8) + ClockDuration::minutes(15)
+    );
+}
+
+#[test]
+fn test_union_and_intersection() {
+    let a = set(&["22:00-06:00"]);
+    let b = set(&["04:00-23:00"]);
+    assert_eq!((&a & &b).to_string(), "04:00-06:00, 22:00-23:00");
+    assert!((&a | &b).is_full_day());
+    assert!((&a & &ClockIntervalSet::new()).is_empty());
+}
+
+#[test]
+fn test_complement() {
+    let hours = set(&["08:00-12:00", "13:00-18:00"]);
+    assert_eq!((!&hours).to_string(), "12:00-13:00, 18:00-08:00");
+    assert_eq!(!&!&hours, hours);
+    assert!((!&ClockIntervalSet::new()).is_full_day());
+    assert!((!&ClockIntervalSet::full_day()).is_empty());
+    assert_eq!(hours.duration() + (!&hours).duration(), ClockDuration::DAY);
+}
@@ -0,0 +1,398 @@
+use std::fmt;
+
+use crate::{
+    write_fraction, Clock, Component, Date, LocalResult, ParseClockError, ParseClockErrorKind,
+    TimeZone, ZonedClock, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SECOND,
+};
+
+struct IsoParser<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> IsoParser<'a> {
+    fn position(&self) -> usize {
+        self.input.len() - self.rest.len()
+    }
+
+    fn error(&self, kind: ParseClockErrorKind) -> ParseClockError {
+        ParseClockError::new(kind, self.position())
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn two_digits(&mut self, component: Component) -> Result<i32, ParseClockError> {
+        match self.rest.as_bytes() {
+            [a @ b'0'..=b'9', b @ b'0'..=b'9', ..] => {
+                self.rest = &self.rest[2..];
+                Ok(((a - b'0') * 10 + (b - b'0')) as i32)
+            }
+            _ => Err(self.error(ParseClockErrorKind::NotANumber(component))),
+        }
+    }
+
+    fn starts_with_digit(&self) -> bool {
+        self.rest.starts_with(|c: char| c.is_ascii_digit())
+    }
+
+    /// A decimal fraction after `.` or `,`, as a multiple of `unit` nanos.
+    fn fraction(&mut self, unit: i64) -> Result<i64, ParseClockError> {
+        if !(self.eat('.') || self.eat(',')) {
+            return Ok(0);
+        }
+        let len = self.rest.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return Err(self.error(ParseClockErrorKind::MissingComponent(Component::Fraction)));
+        }
+        if len > 9 {
+            self.rest = &self.rest[9..];
+            return Err(self.error(ParseClockErrorKind::OutOfRange(Component::Fraction)));
+        }
+        let (digits, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        let digits: i64 = digits.parse().expect("only ASCII digits");
+        Ok((digits as i128 * unit as i128 / 10_i128.pow(len as u32)) as i64)
+    }
+
+    /// `[T]hh[:mm[:ss]][.f]` or `[T]hh[mm[ss]][.f]`, where the fraction
+    /// belongs to the last component given.
+    fn time(&mut self) -> Result<Clock, ParseClockError> {
+        if self.rest.is_empty() {
+            return Err(self.error(ParseClockErrorKind::Empty));
+        }
+        self.eat('T');
+        let hours_at = self.position();
+        let hours = self.two_digits(Component::Hour)?;
+        let extended = self.rest.starts_with(':');
+        let mut components = vec![(Component::Hour, hours_at, hours)];
+        for component in [Component::Minute, Component::Second] {
+            if extended {
+                if !self.rest.starts_with(':') {
+                    break;
+                }
+                self.eat(':');
+            } else if !self.starts_with_digit() {
+                break;
+            }
+            let position = self.position();
+            components.push((component, position, self.two_digits(component)?));
+        }
+        let unit = match components.len() {
+            1 => NANOS_PER_HOUR,
+            2 => NANOS_PER_MINUTE,
+            _ => NANOS_PER_SECOND,
+        };
+        let fraction = self.fraction(unit)?;
+        for &(component, position, value) in &components {
+            // 24:00 is the end of the day, which is midnight on a clock.
+            let end_of_day = component == Component::Hour
+                && value == 24
+                && fraction == 0
+                && components[1..].iter().all(|&(_, _, value)| value == 0);
+            if !component.range().contains(&value) && !end_of_day {
+                return Err(ParseClockError::new(
+                    ParseClockErrorKind::OutOfRange(component),
+                    position,
+                ));
+            }
+        }
+        let nanos = components
+            .iter()
+            .map(|&(component, _, value)| {
+                value as i64
+                    * match component {
+                        Component::Hour => NANOS_PER_HOUR,
+                        Component::Minute => NANOS_PER_MINUTE,
+                        _ => NANOS_PER_SECOND,
+                    }
+            })
+            .sum::<i64>()
+            + fraction;
+        Ok(Clock::from_nanos_since_midnight(nanos))
+    }
+
+    /// `Z`, `+hh`, `+hhmm` or `+hh:mm` (or with `-`).
+    fn offset(&mut self) -> Result<TimeZone, ParseClockError> {
+        if self.eat('Z') || self.eat('z') {
+            return Ok(TimeZone::utc());
+        }
+        if !self.rest.starts_with(['+', '-']) {
+            return Err(self.error(ParseClockErrorKind::InvalidOffset));
+        }
+        let zone = TimeZone::parse_offset(self.rest)
+            .map_err(|_| self.error(ParseClockErrorKind::InvalidOffset))?;
+        self.rest = "";
+        Ok(zone)
+    }
+
+    fn finish(&self) -> Result<(), ParseClockError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(ParseClockErrorKind::TrailingInput))
+        }
+    }
+}
+
+/// A [`Clock`] formatted as an ISO 8601 time of day, created by
+/// [`Clock::iso8601`] or [`ZonedClock::iso8601_on`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iso8601 {
+    clock: Clock,
+    utc_offset: Option<i32>,
+    basic: bool,
+}
+
+impl Iso8601 {
+    /// Switches to the basic format without separators, e.g. `123000`.
+    pub fn basic(self) -> Self {
+        Self {
+            basic: true,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Iso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.basic { "" } else { ":" };
+        let clock = self.clock;
+        write!(
+            f,
+            "{:0>2}{sep}{:0>2}{sep}{:0>2}",
+            clock.hours(),
+            clock.minutes(),
+            clock.seconds(),
+            sep = separator
+        )?;
+        write_fraction(f, clock.nanos())?;
+        match self.utc_offset {
+            None => Ok(()),
+            Some(0) => write!(f, "Z"),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(
+                    f,
+                    "{}{:0>2}{}{:0>2}",
+                    sign,
+                    offset / 3600,
+                    separator,
+                    offset % 3600 / 60
+                )?;
+                if offset % 60 != 0 {
+                    write!(f, "{}{:0>2}", separator, offset % 60)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Clock {
+    /// Parses an ISO 8601 time of day in extended (`12:30:00`) or basic
+    /// (`123000`) format, with an optional leading `T`, reduced precision
+    /// (`12:30`, `12`) and a decimal fraction of the last component.
+    /// `24:00` is accepted as midnight. Offsets are rejected, see
+    /// [`ZonedClock::parse_iso8601`].
+    pub fn parse_iso8601(s: &str) -> Result<Clock, ParseClockError> {
+        let mut parser = IsoParser { input: s, rest: s };
+        let clock = parser.time()?;
+        parser.finish()?;
+        Ok(clock)
+    }
+
+    /// Formats as ISO 8601 extended time, always with seconds.
+    pub fn iso8601(&self) -> Iso8601 {
+        Iso8601 {
+            clock: *self,
+            utc_offset: None,
+            basic: false,
+        }
+    }
+}
+
+impl ZonedClock {
+    /// Parses an RFC 3339 style time with offset, such as `T12:30:00Z` or
+    /// `12:30:00+02:00`, into a clock in a fixed offset zone.
+    pub fn parse_iso8601(s: &str) -> Result<ZonedClock, ParseClockError> {
+        let mut parser = IsoParser { input: s, rest: s };
+        let clock = parser.time()?;
+        let zone = parser.offset()?;
+        parser.finish()?;
+        Ok(ZonedClock::new(clock, zone))
+    }
+
+    /// Formats with the UTC offset of the reading on `date`, once for each
+    /// offset in a DST overlap. A time skipped by a DST gap gets the offset
+    /// from before the gap, naming the same instant as
+    /// [`ZonedClock::to_utc_on_date`]. Offsets with seconds are written as
+    /// `+hh:mm:ss`.
+    pub fn iso8601_on(&self, date: Date) -> LocalResult<Iso8601> {
+        let local = date.and_clock(self.clock()).unix_seconds();
+        self.zone().local_to_utc(local).map(|utc| Iso8601 {
+            clock: self.clock(),
+            utc_offset: Some((local - utc) as i32),
+            basic: false,
+        })
+    }
+}
+
+#[test]
+fn test_parse_extended_and_basic() {
+    let expected = Clock::with_seconds(12, 30, 5);
+    assert_eq!(Clock::parse_iso8601("12:30:05").unwrap(), expected);
+    assert_eq!(Clock::parse_iso8601("T12:30:05").unwrap(), expected);
+    assert_eq!(Clock::parse_iso8601("123005").unwrap(), expected);
+    assert_eq!(Clock::parse_iso8601("1230").unwrap(), Clock::new(12, 30));
+    assert_eq!(Clock::parse_iso8601("12").unwrap(), Clock::new(12, 0));
+}
+
+#[test]
+fn test_parse_fractions() {
+    assert_eq!(
+        Clock::parse_iso8601("12:30:05,25").unwrap(),
+        Clock::with_nanos(12, 30, 5, 250_000_000)
+    );
+    assert_eq!(
+        Clock::parse_iso8601("12:30.5").unwrap(),
+        Clock::with_seconds(12, 30, 30)
+    );
+    assert_eq!(Clock::parse_iso8601("12.25").unwrap(), Clock::new(12, 15));
+    assert_eq!(
+        Clock::parse_iso8601("12.999999999").unwrap(),
+        Clock::with_nanos(12, 59, 59, 999_996_400)
+    );
+}
+
+#[test]
+fn test_parse_end_of_day() {
+    assert_eq!(Clock::parse_iso8601("24:00:00").unwrap(), Clock::new(0, 0));
+    let err = Clock::parse_iso8601("24:00:01").unwrap_err();
+    assert_eq!(err.kind(), ParseClockErrorKind::OutOfRange(Component::Hour));
+}
+
+#[test]
+fn test_parse_rejects_malformed() {
+    let err = |s: &str| {
+        let err = Clock::parse_iso8601(s).unwrap_err();
+        (err.kind(), err.position())
+    };
+    assert_eq!(err(""), (ParseClockErrorKind::Empty, 0));
+    assert_eq!(
+        err("9:30"),
+        (ParseClockErrorKind::NotANumber(Component::Hour), 0)
+    );
+    assert_eq!(err("12:3000"), (ParseClockErrorKind::TrailingInput, 5));
+    assert_eq!(err("1230:00"), (ParseClockErrorKind::TrailingInput, 4));
+    assert_eq!(
+        err("12:60"),
+        (ParseClockErrorKind::OutOfRange(Component::Minute), 3)
+    );
+    assert_eq!(err("12:30Z"), (ParseClockErrorKind::TrailingInput, 5));
+}
+
+#[test]
+fn test_parse_zoned() {
+    let zoned = ZonedClock::parse_iso8601("T12:30:00Z").unwrap();
+    assert_eq!(zoned.clock(), Clock::new(12, 30));
+    assert_eq!(zoned.zone(), &TimeZone::utc());
+    let zoned = ZonedClock::parse_iso8601("12:30:00+02:00").unwrap();
+    assert_eq!(zoned.zone().name(), "+02:00");
+    let zoned = ZonedClock::parse_iso8601("123000-0530").unwrap();
+    assert_eq!(zoned.zone().name(), "-05:30");
+    let err = ZonedClock::parse_iso8601("12:30:00+2").unwrap_err();
+    assert_eq!(
+        (err.kind(), err.position()),
+        (ParseClockErrorKind::InvalidOffset, 8)
+    );
+    let err = ZonedClock::parse_iso8601("12:30:00").unwrap_err();
+    assert_eq!(err.kind(), ParseClockErrorKind::InvalidOffset);
+}
+
+#[test]
+fn test_format() {
+    let clock = Clock::with_nanos(9, 5, 0, 120_000_000);
+    assert_eq!(clock.iso8601().to_string(), "09:05:00.120");
+    assert_eq!(clock.iso8601().basic().to_string(), "090500.120");
+    let date = Date::from_ymd(2024, 6, 1).unwrap();
+    let zoned = ZonedClock::new(Clock::new(12, 30), TimeZone::utc());
+    assert_eq!(
+        zoned.iso8601_on(date).single().unwrap().to_string(),
+        "12:30:00Z"
+    );
+    let zoned = ZonedClock::new(Clock::new(12, 30), "-05:30".parse().unwrap());
+    assert_eq!(
+        zoned.iso8601_on(date).single().unwrap().to_string(),
+        "12:30:00-05:30"
+    );
+    assert_eq!(
+        zoned.iso8601_on(date).single().unwrap().basic().to_string(),
+        "123000-0530"
+    );
+    let zoned = ZonedClock::new(Clock::new(12, 30), TimeZone::fixed(-5 * 3600 - 30).unwrap());
+    let formatted = zoned.iso8601_on(date).single().unwrap();
+    assert_eq!(formatted.to_string(), "12:30:00-05:00:30");
+    assert_eq!(formatted.basic().to_string(), "123000-050030");
+}
+
+#[test]
+fn test_format_around_dst_changes() {
+    let zoned = ZonedClock::new(Clock::new(2, 30), crate::zone::vienna_like());
+    let spring = Date::from_ymd(2024, 3, 31).unwrap();
+    let gap = zoned.iso8601_on(spring);
+    assert_eq!(
+        gap.map(|iso| iso.to_string()),
+        LocalResult::Gap("02:30:00+01:00".to_string())
+    );
+    assert_eq!(
+        zoned.to_utc_on_date(spring),
+        LocalResult::Gap(spring.and_clock(Clock::new(1, 30)))
+    );
+    let autumn = Date::from_ymd(2024, 10, 27).unwrap();
+    assert_eq!(
+        zoned.iso8601_on(autumn).map(|iso| iso.to_string()),
+        LocalResult::Ambiguous("02:30:00+02:00".to_string(), "02:30:00+01:00".to_string())
+    );
+}
+
+#[test]
+fn test_round_trips_agree_with_from_str() {
+    let clocks = [
+        Clock::new(0, 0),
+        Clock::with_seconds(23, 59, 59),
+        Clock::with_nanos(12, 30, 15, 250_000_000),
+        Clock::with_nanos(1, 2, 3, 4),
+    ];
+    for clock in clocks {
+        let display = clock.to_string();
+        assert_eq!(
+            Clock::parse_iso8601(&display).unwrap(),
+            display.parse::<Clock>().unwrap()
+        );
+        let iso = clock.iso8601().to_string();
+        assert_eq!(Clock::parse_iso8601(&iso).unwrap(), clock);
+        assert_eq!(iso.parse::<Clock>().unwrap(), clock);
+        let basic = clock.iso8601().basic().to_string();
+        assert_eq!(Clock::parse_iso8601(&basic).unwrap(), clock);
+    }
+    for text in ["12:30:00+02:00", "00:00:00.500Z", "235959-1000"] {
+        let zoned = ZonedClock::parse_iso8601(text).unwrap();
+        let date = Date::from_ymd(2024, 1, 1).unwrap();
+        let formatted = zoned.iso8601_on(date).single().unwrap();
+        let formatted = if text.contains(':') {
+            formatted.to_string()
+        } else {
+            formatted.basic().to_string()
+        };
+        assert_eq!(formatted, text);
+    }
+}
@@ -0,0 +1,849 @@
+use std::str::FromStr;
+
+mod angle;
+mod civil;
+pub mod cli;
+mod cron;
+mod date;
+mod digital;
+mod duration;
+mod error;
+pub mod expr;
+mod format;
+mod interval_set;
+mod iso8601;
+mod locale;
+mod natural;
+mod opening_hours;
+mod parse;
+mod range;
+pub mod repl;
+mod scheduler;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod svg;
+mod time_source;
+mod zone;
+
+pub use cron::{CronField, CronIter, CronSchedule, ParseCronError, ParseCronErrorKind};
+pub use date::{Date, DateTime, ParseDateError, ParseDateTimeError, Weekday};
+pub use digital::{BinaryClock, SevenSegment, SEGMENTS};
+pub use duration::{ClockDuration, ClockDurationRangeError};
+pub use error::{ClockRangeError, Component, ParseClockError, ParseClockErrorKind};
+pub use format::{ClockFormat, Display12h};
+pub use interval_set::ClockIntervalSet;
+pub use iso8601::Iso8601;
+pub use locale::{English, French, German, Locale, Spelled};
+pub use opening_hours::{OpeningHours, ParseOpeningHoursError};
+pub use parse::LENIENT_PATTERNS;
+pub use range::{ClockRange, ClockRangeIter};
+pub use scheduler::{Scheduler, TimerHandle};
+pub use svg::{AnalogSvg, HandStyle, Numerals, Ticks};
+pub use time_source::{MockTimeSource, SystemTimeSource, TimeSource};
+pub use zone::{LocalResult, LocalTimeType, TimeZone, TimeZoneError, ZonedClock};
+
+pub(crate) const NANOS_PER_SECOND: i64 = 1_000_000_000;
+pub(crate) const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+pub(crate) const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+pub(crate) const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Clock {
+    hours: i32,
+    minutes: i32,
+    seconds: i32,
+    nanos: i32,
+}
+
+impl Clock {
+    pub fn new(hours: i32, minutes: i32) -> Self {
+        Self::with_nanos(hours, minutes, 0, 0)
+    }
+
+    pub fn with_seconds(hours: i32, minutes: i32, seconds: i32) -> Self {
+        Self::with_nanos(hours, minutes, seconds, 0)
+    }
+
+    pub fn with_nanos(hours: i32, minutes: i32, seconds: i32, nanos: i32) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            nanos,
+        }
+        .normalize()
+    }
+
+    pub fn from_nanos_since_midnight(nanos: i64) -> Self {
+        let nanos = nanos.rem_euclid(NANOS_PER_DAY);
+        Self {
+            hours: (nanos / NANOS_PER_HOUR) as i32,
+            minutes: (nanos % NANOS_PER_HOUR / NANOS_PER_MINUTE) as i32,
+            seconds: (nanos % NANOS_PER_MINUTE / NANOS_PER_SECOND) as i32,
+            nanos: (nanos % NANOS_PER_SECOND) as i32,
+        }
+    }
+
+    pub fn hours(&self) -> i32 {
+        self.hours
+    }
+
+    pub fn minutes(&self) -> i32 {
+        self.minutes
+    }
+
+    pub fn seconds(&self) -> i32 {
+        self.seconds
+    }
+
+    pub fn nanos(&self) -> i32 {
+        self.nanos
+    }
+
+    pub fn nanos_since_midnight(&self) -> i64 {
+        self.hours as i64 * NANOS_PER_HOUR
+            + self.minutes as i64 * NANOS_PER_MINUTE
+            + self.seconds as i64 * NANOS_PER_SECOND
+            + self.nanos as i64
+    }
+
+    fn normalize(self) -> Self {
+        let nanos = self.nanos as i64;
+        let seconds = self.seconds as i64 + nanos.div_euclid(NANOS_PER_SECOND);
+        let minutes = self.minutes as i64 + seconds.div_euclid(60);
+        let hours = self.hours as i64 + minutes.div_euclid(60);
+        Self {
+            hours: hours.rem_euclid(24) as i32,
+            minutes: minutes.rem_euclid(60) as i32,
+            seconds: seconds.rem_euclid(60) as i32,
+            nanos: nanos.rem_euclid(NANOS_PER_SECOND) as i32,
+        }
+    }
+}
+
+impl std::ops::Add<i32> for Clock {
+    type Output = Clock;
+
+    fn add(self, rhs: i32) -> Self::Output {
+        Clock::with_nanos(self.hours, self.minutes + rhs, self.seconds, self.nanos)
+    }
+}
+
+impl std::ops::Sub<i32> for Clock {
+    type Output = Clock;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        Clock::with_nanos(self.hours, self.minutes - rhs, self.seconds, self.nanos)
+    }
+}
+
+/// Formats as `21:30`, or with `{:#}` on a 12 hour dial as `9:30 PM`.
+/// Seconds and fractions are only shown when set, e.g. `21:30:15.250`.
+impl std::fmt::Display for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}:{:0>2}", format::hour12(self.hours), self.minutes)?;
+        } else {
+            write!(f, "{:0>2}:{:0>2}", self.hours, self.minutes)?;
+        }
+        if self.seconds != 0 || self.nanos != 0 {
+            write!(f, ":{:0>2}", self.seconds)?;
+        }
+        write_fraction(f, self.nanos)?;
+        if f.alternate() {
+            f.write_str(if self.hours < 12 { " AM" } else { " PM" })?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `.fff`, `.ffffff` or `.fffffffff`, whichever is the shortest
+/// exact form, or nothing for zero.
+pub(crate) fn write_fraction(f: &mut std::fmt::Formatter<'_>, nanos: i32) -> std::fmt::Result {
+    if nanos == 0 {
+        Ok(())
+    } else if nanos % 1_000_000 == 0 {
+        write!(f, ".{:0>3}", nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        write!(f, ".{:0>6}", nanos / 1_000)
+    } else {
+        write!(f, ".{:0>9}", nanos)
+    }
+}
+
+/// Splits a trailing `am`, `pm`, `a.m.` or `p.m.` and any whitespace before
+/// it off `s`, returning whether it was `pm`.
+fn split_meridiem(s: &str) -> Option<(&str, bool)> {
+    let lower = s.to_ascii_lowercase();
+    [("a.m.", false), ("p.m.", true), ("am", false), ("pm", true)]
+        .into_iter()
+        .find(|(suffix, _)| lower.ends_with(suffix))
+        .map(|(suffix, pm)| (s[..s.len() - suffix.len()].trim_end(), pm))
+}
+
+/// How [`Clock::parse_with_options`] treats components outside of a clock face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Reject components like `99:99` or `-1:30` instead of normalizing them,
+    /// which is what [`FromStr`] does.
+    pub strict: bool,
+}
+
+fn parse_component(
+    s: &str,
+    position: usize,
+    component: Component,
+    options: ParseOptions,
+) -> Result<i32, ParseClockError> {
+    if options.strict {
+        if let Some(idx) = s.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::NotANumber(component),
+                position + idx,
+            ));
+        }
+    }
+    let value = s
+        .parse()
+        .map_err(|err| ParseClockError::from_int(component, position, err))?;
+    if options.strict && !component.range().contains(&value) {
+        return Err(ParseClockError::new(
+            ParseClockErrorKind::OutOfRange(component),
+            position,
+        ));
+    }
+    Ok(value)
+}
+
+fn parse_fraction(s: &str, position: usize) -> Result<i32, ParseClockError> {
+    let fraction = Component::Fraction;
+    if let Some(idx) = s.find(|c: char| !c.is_ascii_digit()) {
+        return Err(ParseClockError::new(
+            ParseClockErrorKind::NotANumber(fraction),
+            position + idx,
+        ));
+    }
+    if s.is_empty() {
+        return Err(ParseClockError::new(
+            ParseClockErrorKind::MissingComponent(fraction),
+            position,
+        ));
+    }
+    if s.len() > 9 {
+        return Err(ParseClockError::new(
+            ParseClockErrorKind::OutOfRange(fraction),
+            position + 9,
+        ));
+    }
+    let digits = parse_component(s, position, fraction, ParseOptions::default())?;
+    Ok(digits * 10_i32.pow(9 - s.len() as u32))
+}
+
+impl Clock {
+    pub fn try_new(hours: i32, minutes: i32) -> Result<Self, ClockRangeError> {
+        Self::try_with_nanos(hours, minutes, 0, 0)
+    }
+
+    /// Like [`Clock::with_nanos`], but fails instead of wrapping components
+    /// that are out of range.
+    pub fn try_with_nanos(
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+        nanos: i32,
+    ) -> Result<Self, ClockRangeError> {
+        let components = [
+            (Component::Hour, hours),
+            (Component::Minute, minutes),
+            (Component::Second, seconds),
+            (Component::Fraction, nanos),
+        ];
+        for (component, value) in components {
+            if !component.range().contains(&value) {
+                return Err(ClockRangeError::new(component, value));
+            }
+        }
+        Ok(Self {
+            hours,
+            minutes,
+            seconds,
+            nanos,
+        })
+    }
+
+    /// Parses `H:M`, `H:M:S` or `H:M:S.f`, optionally followed by `am`,
+    /// `pm`, `a.m.` or `p.m.` in any case. With a suffix the hour must be
+    /// `1..=12`, `12 AM` being midnight and `12 PM` noon, and components are
+    /// validated as if [`ParseOptions::strict`] was set.
+    pub fn parse_with_options(s: &str, options: ParseOptions) -> Result<Self, ParseClockError> {
+        if s.is_empty() {
+            return Err(ParseClockError::new(ParseClockErrorKind::Empty, 0));
+        }
+        let Some((time, pm)) = split_meridiem(s) else {
+            return Self::parse_24h(s, options);
+        };
+        if time.is_empty() {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::MissingComponent(Component::Hour),
+                0,
+            ));
+        }
+        let clock = Self::parse_24h(time, ParseOptions { strict: true })?;
+        if !(1..=12).contains(&clock.hours) {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::OutOfRange(Component::Hour),
+                0,
+            ));
+        }
+        let hours = clock.hours % 12 + if pm { 12 } else { 0 };
+        Ok(Clock { hours, ..clock })
+    }
+
+    fn parse_24h(s: &str, options: ParseOptions) -> Result<Self, ParseClockError> {
+        let Some((hours, rest)) = s.split_once(':') else {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::MissingSeparator,
+                s.len(),
+            ));
+        };
+        let minutes_at = hours.len() + 1;
+        let hours = parse_component(hours, 0, Component::Hour, options)?;
+        let Some((minutes, seconds)) = rest.split_once(':') else {
+            let minutes = parse_component(rest, minutes_at, Component::Minute, options)?;
+            return Ok(Clock::new(hours, minutes));
+        };
+        let seconds_at = minutes_at + minutes.len() + 1;
+        let minutes = parse_component(minutes, minutes_at, Component::Minute, options)?;
+        if let Some(idx) = seconds.find(':') {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::TrailingInput,
+                seconds_at + idx,
+            ));
+        }
+        let (seconds, nanos) = match seconds.split_once('.') {
+            Some((seconds, fraction)) => (
+                seconds,
+                parse_fraction(fraction, seconds_at + seconds.len() + 1)?,
+            ),
+            None => (seconds, 0),
+        };
+        let seconds = parse_component(seconds, seconds_at, Component::Second, options)?;
+        Ok(Clock::with_nanos(hours, minutes, seconds, nanos))
+    }
+
+    pub fn parse_strict(s: &str) -> Result<Self, ParseClockError> {
+        Self::parse_with_options(s, ParseOptions { strict: true })
+    }
+}
+
+impl FromStr for Clock {
+    type Err = ParseClockError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Clock::parse_with_options(s, ParseOptions::default())
+    }
+}
+
+//
+// Clock Creation
+//
+
+#[test]
+fn test_on_the_hour() {
+    let clock = Clock::new(8, 0);
+    assert_eq!(clock.hours, 8);
+    assert_eq!(clock.minutes, 0);
+}
+
+#[test]
+fn test_midnight_is_zero_hours() {
+    let clock: Clock = Clock::new(24, 0);
+    assert_eq!(clock.hours, 0);
+}
+
+#[test]
+fn test_hour_rolls_over() {
+    let clock = Clock::new(25, 0);
+    assert_eq!(clock.hours, 1);
+}
+
+#[test]
+fn test_hour_rolls_over_continuously() {
+    let clock = Clock::new(100, 0);
+    assert_eq!(clock.hours, 4);
+}
+
+#[test]
+
+fn test_sixty_minutes_is_next_hour() {
+    let clock = Clock::new(1, 60);
+    assert_eq!(clock.hours, 2);
+}
+
+#[test]
+fn test_minutes_roll_over() {
+    let clock = Clock::new(0, 160);
+    assert_eq!(clock.hours, 2);
+    assert_eq!(clock.minutes, 40);
+}
+
+#[test]
+fn test_minutes_roll_over_continuously() {
+    let clock = Clock::new(0, 1723);
+    assert_eq!(clock.hours, 4);
+    assert_eq!(clock.minutes, 43);
+}
+
+#[test]
+fn test_hours_and_minutes_roll_over() {
+    let clock = Clock::new(25, 160);
+    assert_eq!(clock.hours, 3);
+    assert_eq!(clock.minutes, 40);
+}
+
+#[test]
+fn test_hours_and_minutes_roll_over_continuously() {
+    let clock = Clock::new(201, 3001);
+    assert_eq!(clock.hours, 11);
+    assert_eq!(clock.minutes, 1);
+}
+
+#[test]
+fn test_hours_and_minutes_roll_over_to_exactly_midnight() {
+    let clock = Clock::new(72, 8640);
+    assert_eq!(clock.hours, 0);
+    assert_eq!(clock.minutes, 0);
+}
+
+#[test]
+fn test_negative_hour() {
+    let clock = Clock::new(-1, 15);
+    assert_eq!(clock.hours, 23);
+    assert_eq!(clock.minutes, 15);
+}
+
+#[test]
+fn test_negative_hour_roll_over() {
+    let clock = Clock::new(-25, 0);
+    assert_eq!(clock.hours, 23);
+    assert_eq!(clock.minutes, 0);
+}
+
+#[test]
+fn test_negative_hour_roll_over_continuously() {
+    let clock = Clock::new(-91, 0);
+    assert_eq!(clock.hours, 5);
+    assert_eq!(clock.minutes, 0);
+}
+
+#[test]
+fn test_negative_minutes() {
+    let clock = Clock::new(1, -40);
+    assert_eq!(clock.hours, 0);
+    assert_eq!(clock.minutes, 20);
+}
+
+#[test]
+fn test_negative_minutes_roll_over() {
+    let clock = Clock::new(1, -160);
+    assert_eq!(clock.hours, 22);
+    assert_eq!(clock.minutes, 20);
+}
+
+#[test]
+fn test_negative_minutes_roll_over_continuously() {
+    let clock = Clock::new(1, -4820);
+    assert_eq!(clock.hours, 16);
+    assert_eq!(clock.minutes, 40);
+}
+
+#[test]
+fn test_negative_sixty_minutes_is_prev_hour() {
+    let clock = Clock::new(2, -60);
+    assert_eq!(clock.hours, 1);
+    assert_eq!(clock.minutes, 0);
+}
+
+#[test]
+fn test_negative_hour_and_minutes_both_roll_over() {
+    let clock = Clock::new(-25, -160);
+    assert_eq!(clock.hours, 20);
+    assert_eq!(clock.minutes, 20);
+}
+
+#[test]
+fn test_negative_hour_and_minutes_both_roll_over_continuously() {
+    let clock = Clock::new(-121, -5810);
+    assert_eq!(clock.hours, 22);
+    assert_eq!(clock.minutes, 10);
+}
+
+#[test]
+fn test_zero_hour_and_negative_minutes() {
+    let clock = Clock::new(0, -22);
+    assert_eq!(clock.hours, 23);
+    assert_eq!(clock.minutes, 38);
+}
+
+//
+// Clock Math
+//
+
+#[test]
+fn test_add_minutes() {
+    let clock = Clock::new(10, 0) + 3;
+    assert_eq!(clock.hours, 10);
+    assert_eq!(clock.minutes, 3);
+}
+
+#[test]
+fn test_add_no_minutes() {
+    let clock = Clock::new(6, 41) + 0;
+    assert_eq!(clock.hours, 6);
+    assert_eq!(clock.minutes, 41);
+}
+
+#[test]
+fn test_add_to_next_hour() {
+    let clock = Clock::new(0, 45) + 40;
+    assert_eq!(clock.hours, 1);
+    assert_eq!(clock.minutes, 25);
+}
+
+#[test]
+fn test_add_more_than_one_hour() {
+    let clock = Clock::new(10, 0) + 61;
+    assert_eq!(clock.hours, 11);
+    assert_eq!(clock.minutes, 1);
+}
+
+#[test]
+fn test_add_more_than_two_hours_with_carry() {
+    let clock = Clock::new(0, 45) + 160;
+    assert_eq!(clock.hours, 3);
+    assert_eq!(clock.minutes, 25);
+}
+
+#[test]
+fn test_add_across_midnight() {
+    let clock = Clock::new(23, 59) + 2;
+    assert_eq!(clock.hours, 0);
+    assert_eq!(clock.minutes, 1);
+}
+
+#[test]
+fn test_add_more_than_one_day() {
+    let clock = Clock::new(5, 32) + 1500;
+    assert_eq!(clock.hours, 6);
+    assert_eq!(clock.minutes, 32);
+}
+
+#[test]
+fn test_add_more_than_two_days() {
+    let clock = Clock::new(1, 1) + 3500;
+    assert_eq!(clock.hours, 11);
+    assert_eq!(clock.minutes, 21);
+}
+
+#[test]
+fn test_subtract_minutes() {
+    let clock = Clock::new(10, 3) - 3;
+    assert_eq!(clock.hours, 10);
+    assert_eq!(clock.minutes, 0);
+}
+
+#[test]
+fn test_subtract_to_previous_hour() {
+    let clock = Clock::new(10, 3) - 30;
+    assert_eq!(clock.hours, 9);
+    assert_eq!(clock.minutes, 33);
+}
+
+#[test]
+fn test_subtract_more_than_an_hour() {
+    let clock = Clock::new(10, 3) - 70;
+    assert_eq!(clock.hours, 8);
+    assert_eq!(clock.minutes, 53);
+}
+
+#[test]
+fn test_subtract_across_midnight() {
+    let clock = Clock::new(0, 3) - 4;
+    assert_eq!(clock.hours, 23);
+    assert_eq!(clock.minutes, 59);
+}
+
+#[test]
+fn test_subtract_more_than_two_hours() {
+    let clock = Clock::new(0, 0) - 160;
+    assert_eq!(clock.hours, 21);
+    assert_eq!(clock.minutes, 20);
+}
+
+#[test]
+fn test_subtract_more_than_two_hours_with_borrow() {
+    let clock = Clock::new(6, 15) - 160;
+    assert_eq!(clock.hours, 3);
+    assert_eq!(clock.minutes, 35);
+}
+
+#[test]
+fn test_subtract_more_than_one_day() {
+    let clock = Clock::new(5, 32) - 1500;
+    assert_eq!(clock.hours, 4);
+    assert_eq!(clock.minutes, 32);
+}
+
+#[test]
+fn test_subtract_mores_than_two_days() {
+    let clock = Clock::new(2, 20) - 3000;
+    assert_eq!(clock.hours, 0);
+    assert_eq!(clock.minutes, 20);
+}
+
+#[test]
+fn test_parse_string_into_clock() {
+    let clock: Result<Clock, ParseClockError> = "12:30".parse();
+    assert!(clock.is_ok());
+    let clock = clock.unwrap();
+    assert_eq!(clock.hours, 12);
+    assert_eq!(clock.minutes, 30);
+}
+
+#[test]
+fn test_parse_invalid_string_to_error() {
+    let clock: Result<Clock, ParseClockError> = "1230".parse();
+    assert!(clock.is_err());
+}
+
+#[test]
+fn test_parse_invalid_string_with_chars_to_error() {
+    let clock: Result<Clock, ParseClockError> = "aa:bb".parse();
+    assert!(clock.is_err());
+}
+
+//
+// Seconds and sub-second precision
+//
+
+#[test]
+fn test_seconds_roll_over_into_minutes() {
+    let clock = Clock::with_seconds(10, 59, 75);
+    assert_eq!(clock.hours, 11);
+    assert_eq!(clock.minutes, 0);
+    assert_eq!(clock.seconds, 15);
+}
+
+#[test]
+fn test_negative_nanos_borrow_from_seconds() {
+    let clock = Clock::with_nanos(0, 0, 0, -1);
+    assert_eq!(clock.hours, 23);
+    assert_eq!(clock.minutes, 59);
+    assert_eq!(clock.seconds, 59);
+    assert_eq!(clock.nanos, 999_999_999);
+}
+
+#[test]
+fn test_large_hours_do_not_overflow() {
+    let clock = Clock::with_nanos(i32::MAX, i32::MAX, i32::MAX, i32::MAX);
+    assert_eq!(
+        clock,
+        Clock::from_nanos_since_midnight(clock.nanos_since_midnight())
+    );
+}
+
+#[test]
+fn test_add_minutes_keeps_seconds() {
+    let clock = Clock::with_nanos(23, 59, 30, 250_000_000) + 2;
+    assert_eq!(clock, Clock::with_nanos(0, 1, 30, 250_000_000));
+}
+
+#[test]
+fn test_nanos_since_midnight_round_trip() {
+    let clock = Clock::with_nanos(12, 30, 15, 250_000_000);
+    assert_eq!(clock.nanos_since_midnight(), 45_015_250_000_000);
+    assert_eq!(Clock::from_nanos_since_midnight(45_015_250_000_000), clock);
+    assert_eq!(
+        Clock::from_nanos_since_midnight(-1),
+        Clock::with_nanos(23, 59, 59, 999_999_999)
+    );
+}
+
+#[test]
+fn test_display_omits_zero_seconds() {
+    assert_eq!(Clock::new(8, 5).to_string(), "08:05");
+    assert_eq!(Clock::with_seconds(8, 5, 9).to_string(), "08:05:09");
+}
+
+#[test]
+fn test_display_fraction_precision() {
+    assert_eq!(
+        Clock::with_nanos(12, 30, 15, 250_000_000).to_string(),
+        "12:30:15.250"
+    );
+    assert_eq!(
+        Clock::with_nanos(12, 30, 0, 1_000).to_string(),
+        "12:30:00.000001"
+    );
+    assert_eq!(
+        Clock::with_nanos(12, 30, 0, 7).to_string(),
+        "12:30:00.000000007"
+    );
+}
+
+#[test]
+fn test_display_12h() {
+    assert_eq!(format!("{:#}", Clock::new(10, 10)), "10:10 AM");
+    assert_eq!(format!("{:#}", Clock::new(12, 0)), "12:00 PM");
+    assert_eq!(format!("{:#}", Clock::new(0, 0)), "12:00 AM");
+    assert_eq!(format!("{:#}", Clock::new(21, 5)), "9:05 PM");
+    assert_eq!(
+        format!("{:#}", Clock::with_nanos(23, 59, 59, 500_000_000)),
+        "11:59:59.500 PM"
+    );
+    assert_eq!(Clock::new(0, 30).display_12h().to_string(), "12:30 AM");
+}
+
+#[test]
+fn test_parse_12h() {
+    let parse = |s: &str| s.parse::<Clock>().unwrap();
+    assert_eq!(parse("10:10 AM"), Clock::new(10, 10));
+    assert_eq!(parse("10:10pm"), Clock::new(22, 10));
+    assert_eq!(parse("12:00 AM"), Clock::new(0, 0));
+    assert_eq!(parse("12:00 PM"), Clock::new(12, 0));
+    assert_eq!(parse("12:30 a.m."), Clock::new(0, 30));
+    assert_eq!(parse("1:15:30 P.M."), Clock::with_seconds(13, 15, 30));
+    for minute in (0..24 * 60).step_by(7) {
+        let clock = Clock::new(0, minute);
+        assert_eq!(parse(&format!("{:#}", clock)), clock);
+    }
+    let err = |input: &str| {
+        let err = input.parse::<Clock>().unwrap_err();
+        (err.kind(), err.position())
+    };
+    assert_eq!(
+        err("13:00 pm"),
+        (ParseClockErrorKind::OutOfRange(Component::Hour), 0)
+    );
+    assert_eq!(
+        err("0:30 am"),
+        (ParseClockErrorKind::OutOfRange(Component::Hour), 0)
+    );
+    assert_eq!(
+        err("11:75 pm"),
+        (ParseClockErrorKind::OutOfRange(Component::Minute), 3)
+    );
+    assert_eq!(
+        err("pm"),
+        (ParseClockErrorKind::MissingComponent(Component::Hour), 0)
+    );
+}
+
+#[test]
+fn test_parse_seconds_and_fraction() {
+    let clock: Clock = "12:30:15.25".parse().unwrap();
+    assert_eq!(clock, Clock::with_nanos(12, 30, 15, 250_000_000));
+    let clock: Clock = "12:30:15".parse().unwrap();
+    assert_eq!(clock, Clock::with_seconds(12, 30, 15));
+}
+
+#[test]
+fn test_parse_display_round_trip() {
+    for input in ["00:00", "12:30:15", "12:30:15.250", "23:59:59.999999999"] {
+        assert_eq!(input.parse::<Clock>().unwrap().to_string(), input);
+    }
+}
+
+#[test]
+fn test_parse_invalid_fraction_to_error() {
+    assert!("12:30:15.".parse::<Clock>().is_err());
+    assert!("12:30:15.1234567890".parse::<Clock>().is_err());
+    assert!("12:30:15.+5".parse::<Clock>().is_err());
+    assert!("12:30:15:00".parse::<Clock>().is_err());
+}
+
+#[test]
+fn test_parse_error_kinds_and_positions() {
+    let err = |input: &str| {
+        let err = input.parse::<Clock>().unwrap_err();
+        (err.kind(), err.position())
+    };
+    assert_eq!(err(""), (ParseClockErrorKind::Empty, 0));
+    assert_eq!(err("1230"), (ParseClockErrorKind::MissingSeparator, 4));
+    assert_eq!(
+        err("aa:bb"),
+        (ParseClockErrorKind::NotANumber(Component::Hour), 0)
+    );
+    assert_eq!(
+        err("12:bb"),
+        (ParseClockErrorKind::NotANumber(Component::Minute), 3)
+    );
+    assert_eq!(
+        err("12:30:99999999999"),
+        (ParseClockErrorKind::OutOfRange(Component::Second), 6)
+    );
+    assert_eq!(
+        err("12:30:15.2x"),
+        (ParseClockErrorKind::NotANumber(Component::Fraction), 10)
+    );
+    assert_eq!(err("12:30:15:00"), (ParseClockErrorKind::TrailingInput, 8));
+}
+
+#[test]
+fn test_parse_error_keeps_source() {
+    use std::error::Error;
+    let err = "12:3x".parse::<Clock>().unwrap_err();
+    assert!(err
+        .source()
+        .unwrap()
+        .downcast_ref::<std::num::ParseIntError>()
+        .is_some());
+    assert_eq!(
+        err.to_string(),
+        "Error parse string to Clock: minute is not a number at byte 3"
+    );
+    let err = "1230".parse::<Clock>().unwrap_err();
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_try_new_validates() {
+    assert_eq!(Clock::try_new(23, 59).unwrap(), Clock::new(23, 59));
+    let err = Clock::try_new(24, 0).unwrap_err();
+    assert_eq!(err.component(), Component::Hour);
+    assert_eq!(err.value(), 24);
+    assert_eq!(
+        Clock::try_with_nanos(12, 0, 60, 0).unwrap_err().component(),
+        Component::Second
+    );
+    assert!(Clock::try_with_nanos(12, 0, 0, 1_000_000_000).is_err());
+    assert!(Clock::try_new(0, -1).is_err());
+}
+
+#[test]
+fn test_default_parse_normalizes() {
+    assert_eq!("99:99".parse::<Clock>().unwrap(), Clock::new(4, 39));
+}
+
+#[test]
+fn test_strict_parse_rejects_out_of_range() {
+    let err = Clock::parse_strict("99:99").unwrap_err();
+    assert_eq!(err.kind(), ParseClockErrorKind::OutOfRange(Component::Hour));
+    assert_eq!(err.position(), 0);
+    let err = Clock::parse_strict("12:60").unwrap_err();
+    assert_eq!(
+        (err.kind(), err.position()),
+        (ParseClockErrorKind::OutOfRange(Component::Minute), 3)
+    );
+    let err = Clock::parse_strict("12:30:60").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ParseClockErrorKind::OutOfRange(Component::Second)
+    );
+    let err = Clock::parse_strict("-1:30").unwrap_err();
+    assert_eq!(err.kind(), ParseClockErrorKind::NotANumber(Component::Hour));
+    assert_eq!(
+        Clock::parse_strict("23:59:59.5").unwrap(),
+        Clock::with_nanos(23, 59, 59, 500_000_000)
+    );
+}
@@ -0,0 +1,348 @@
+//! Spelling out a [`Clock`] the way people say it, for voice output and
+//! screen readers: `ten past ten`, `zehn nach zehn`, `dix heures dix`.
+
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    natural::{TENS, UNITS},
+    Clock,
+};
+
+/// Spells out a [`Clock`] in a natural language, the way it is said
+/// rather than read off a display. Implement it to plug in more locales.
+pub trait Locale {
+    /// Writes `clock` as words. Implementations decide how to round seconds
+    /// and whether to tell morning from evening.
+    fn write_clock(&self, clock: Clock, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<L: Locale + ?Sized> Locale for &L {
+    fn write_clock(&self, clock: Clock, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).write_clock(clock, f)
+    }
+}
+
+/// A [`Clock`] paired with a [`Locale`], spelled out only when displayed.
+/// Created by [`Clock::spelled`].
+#[derive(Debug, Clone, Copy)]
+pub struct Spelled<'a, L: ?Sized> {
+    clock: Clock,
+    locale: &'a L,
+}
+
+impl<L: Locale + ?Sized> fmt::Display for Spelled<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.locale.write_clock(self.clock, f)
+    }
+}
+
+impl Clock {
+    /// Spells out the clock in `locale`, e.g. `ten past ten` in [`English`],
+    /// `zehn nach zehn` in [`German`] or `dix heures dix` in [`French`].
+    /// Seconds are dropped.
+    pub fn spelled<'a, L: Locale + ?Sized>(&self, locale: &'a L) -> Spelled<'a, L> {
+        Spelled {
+            clock: *self,
+            locale,
+        }
+    }
+}
+
+/// The hour the spoken form refers to, which is the next one once the
+/// minutes count down to it.
+fn spoken_hour(clock: Clock, to_from: i32) -> i32 {
+    if clock.minutes() < to_from {
+        clock.hours()
+    } else {
+        (clock.hours() + 1) % 24
+    }
+}
+
+/// English as understood by [`Clock::parse_natural`], e.g. `quarter past
+/// nine`. Hours are on a 12 hour dial, so add
+/// [`English::with_day_period`] to keep morning and evening apart.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English {
+    day_period: bool,
+}
+
+impl English {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `in the morning`, `in the afternoon`, `in the evening` or
+    /// `at night`.
+    pub fn with_day_period(self) -> Self {
+        Self { day_period: true }
+    }
+}
+
+fn english_number(n: i32) -> Cow<'static, str> {
+    match n {
+        0..=19 => Cow::Borrowed(UNITS[n as usize]),
+        _ if n % 10 == 0 => Cow::Borrowed(TENS[n as usize / 10]),
+        _ => Cow::Owned(format!(
+            "{}-{}",
+            TENS[n as usize / 10],
+            UNITS[n as usize % 10]
+        )),
+    }
+}
+
+fn english_hour(hour: i32) -> Cow<'static, str> {
+    match hour {
+        0 => Cow::Borrowed("midnight"),
+        12 => Cow::Borrowed("noon"),
+        _ => english_number(hour % 12),
+    }
+}
+
+impl Locale for English {
+    fn write_clock(&self, clock: Clock, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minutes = clock.minutes();
+        let hour = spoken_hour(clock, 31);
+        let name = english_hour(hour);
+        match minutes {
+            0 if hour % 12 == 0 => write!(f, "{}", name)?,
+            0 => write!(f, "{} o'clock", name)?,
+            15 => write!(f, "quarter past {}", name)?,
+            30 => write!(f, "half past {}", name)?,
+            45 => write!(f, "quarter to {}", name)?,
+            _ => {
+                let (count, relation) = if minutes < 31 {
+                    (minutes, "past")
+                } else {
+                    (60 - minutes, "to")
+                };
+                let unit = match count {
+                    1 => " minute",
+                    _ if count % 5 == 0 => "",
+                    _ => " minutes",
+                };
+                write!(f, "{}{} {} {}", english_number(count), unit, relation, name)?
+            }
+        }
+        if self.day_period {
+            match hour {
+                1..=11 => f.write_str(" in the morning")?,
+                13..=17 => f.write_str(" in the afternoon")?,
+                18..=20 => f.write_str(" in the evening")?,
+                21..=23 => f.write_str(" at night")?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Up to twenty, as colloquial German counts minutes at most that far from
+/// the full or half hour.
+const GERMAN_NUMBERS: [&str; 21] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+    "zwanzig",
+];
+
+/// Colloquial German counting towards the half hour: `Viertel nach zehn`,
+/// `fünf vor halb elf`, `halb elf`, `Viertel vor elf`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct German;
+
+impl Locale for German {
+    fn write_clock(&self, clock: Clock, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minutes = clock.minutes();
+        let hour = spoken_hour(clock, 21);
+        let name = match hour % 12 {
+            0 => "zwölf",
+            dial => GERMAN_NUMBERS[dial as usize],
+        };
+        let count = |f: &mut fmt::Formatter<'_>, count: i32| match count {
+            1 => f.write_str("eine Minute"),
+            _ if count % 5 == 0 => f.write_str(GERMAN_NUMBERS[count as usize]),
+            _ => write!(f, "{} Minuten", GERMAN_NUMBERS[count as usize]),
+        };
+        match minutes {
+            0 if clock.hours() == 0 => f.write_str("Mitternacht"),
+            0 if clock.hours() == 12 => f.write_str("Mittag"),
+            0 if hour % 12 == 1 => f.write_str("ein Uhr"),
+            0 => write!(f, "{} Uhr", name),
+            15 => write!(f, "Viertel nach {}", name),
+            30 => write!(f, "halb {}", name),
+            45 => write!(f, "Viertel vor {}", name),
+            1..=20 => {
+                count(f, minutes)?;
+                write!(f, " nach {}", name)
+            }
+            21..=29 => {
+                count(f, 30 - minutes)?;
+                write!(f, " vor halb {}", name)
+            }
+            31..=39 => {
+                count(f, minutes - 30)?;
+                write!(f, " nach halb {}", name)
+            }
+            _ => {
+                count(f, 60 - minutes)?;
+                write!(f, " vor {}", name)
+            }
+        }
+    }
+}
+
+const FRENCH_UNITS: [&str; 17] = [
+    "zéro", "une", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+const FRENCH_TENS: [&str; 6] = ["", "dix", "vingt", "trente", "quarante", "cinquante"];
+
+/// Feminine, as both `heure` and `minute` are.
+fn french_number(n: i32) -> Cow<'static, str> {
+    let (tens, units) = (n as usize / 10, n as usize % 10);
+    match n {
+        0..=16 => Cow::Borrowed(FRENCH_UNITS[n as usize]),
+        _ if units == 0 => Cow::Borrowed(FRENCH_TENS[tens]),
+        17..=19 => Cow::Owned(format!("dix-{}", FRENCH_UNITS[units])),
+        _ if units == 1 => Cow::Owned(format!("{} et une", FRENCH_TENS[tens])),
+        _ => Cow::Owned(format!("{}-{}", FRENCH_TENS[tens], FRENCH_UNITS[units])),
+    }
+}
+
+/// Conversational French on a 12 hour dial with `midi` and `minuit`:
+/// `dix heures dix`, `midi et quart`, `onze heures moins le quart`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct French;
+
+impl Locale for French {
+    fn write_clock(&self, clock: Clock, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minutes = clock.minutes();
+        let hour = spoken_hour(clock, 35);
+        match hour {
+            0 => f.write_str("minuit")?,
+            12 => f.write_str("midi")?,
+            1 | 13 => f.write_str("une heure")?,
+            _ => write!(f, "{} heures", french_number(hour % 12))?,
+        }
+        match minutes {
+            0 => Ok(()),
+            15 => f.write_str(" et quart"),
+            // `demi` agrees with the masculine `midi` and `minuit`.
+            30 if hour % 12 == 0 => f.write_str(" et demi"),
+            30 => f.write_str(" et demie"),
+            45 => f.write_str(" moins le quart"),
+            1..=34 => write!(f, " {}", french_number(minutes)),
+            _ => write!(f, " moins {}", french_number(60 - minutes)),
+        }
+    }
+}
+
+#[test]
+fn test_english() {
+    let spell = |h, m| Clock::new(h, m).spelled(&English::new()).to_string();
+    assert_eq!(spell(10, 10), "ten past ten");
+    assert_eq!(spell(9, 15), "quarter past nine");
+    assert_eq!(spell(19, 30), "half past seven");
+    assert_eq!(spell(10, 45), "quarter to eleven");
+    assert_eq!(spell(23, 50), "ten to midnight");
+    assert_eq!(spell(11, 53), "seven minutes to noon");
+    assert_eq!(spell(0, 1), "one minute past midnight");
+    assert_eq!(spell(14, 25), "twenty-five past two");
+    assert_eq!(spell(7, 0), "seven o'clock");
+    assert_eq!(spell(12, 0), "noon");
+    assert_eq!(spell(0, 0), "midnight");
+    let english = English::new().with_day_period();
+    assert_eq!(
+        Clock::new(21, 40).spelled(&english).to_string(),
+        "twenty to ten at night"
+    );
+}
+
+#[test]
+fn test_german() {
+    let spell = |h, m| Clock::new(h, m).spelled(&German).to_string();
+    assert_eq!(spell(10, 10), "zehn nach zehn");
+    assert_eq!(spell(10, 15), "Viertel nach zehn");
+    assert_eq!(spell(10, 25), "fünf vor halb elf");
+    assert_eq!(spell(10, 30), "halb elf");
+    assert_eq!(spell(22, 37), "sieben Minuten nach halb elf");
+    assert_eq!(spell(10, 45), "Viertel vor elf");
+    assert_eq!(spell(12, 59), "eine Minute vor eins");
+    assert_eq!(spell(13, 0), "ein Uhr");
+    assert_eq!(spell(0, 0), "Mitternacht");
+    assert_eq!(spell(12, 0), "Mittag");
+    assert_eq!(spell(23, 55), "fünf vor zwölf");
+    assert_eq!(spell(9, 20), "zwanzig nach neun");
+    assert_eq!(spell(9, 41), "neunzehn Minuten vor zehn");
+    for minute in 0..24 * 60 {
+        assert!(!Clock::new(0, minute)
+            .spelled(&German)
+            .to_string()
+            .is_empty());
+    }
+}
+
+#[test]
+fn test_french() {
+    let spell = |h, m| Clock::new(h, m).spelled(&French).to_string();
+    assert_eq!(spell(10, 10), "dix heures dix");
+    assert_eq!(spell(10, 15), "dix heures et quart");
+    assert_eq!(spell(10, 30), "dix heures et demie");
+    assert_eq!(spell(12, 30), "midi et demi");
+    assert_eq!(spell(10, 45), "onze heures moins le quart");
+    assert_eq!(spell(23, 40), "minuit moins vingt");
+    assert_eq!(spell(13, 21), "une heure vingt et une");
+    assert_eq!(spell(7, 34), "sept heures trente-quatre");
+    assert_eq!(spell(0, 0), "minuit");
+}
+
+#[test]
+fn test_english_round_trips_through_parse_natural() {
+    let dial = English::new();
+    let day = English::new().with_day_period();
+    for minute in 0..24 * 60 {
+        let clock = Clock::new(0, minute);
+        let spelled = clock.spelled(&day).to_string();
+        assert_eq!(Clock::parse_natural(&spelled), Ok(clock), "{}", spelled);
+        let spelled = clock.spelled(&dial).to_string();
+        let parsed = Clock::parse_natural(&spelled).unwrap();
+        assert_eq!(parsed.hours() % 12, clock.hours() % 12, "{}", spelled);
+        assert_eq!(parsed.minutes(), clock.minutes(), "{}", spelled);
+    }
+}
+
+#[test]
+fn test_custom_locale() {
+    struct Digits;
+
+    impl Locale for Digits {
+        fn write_clock(&self, clock: Clock, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}h{:0>2}", clock.hours(), clock.minutes())
+        }
+    }
+
+    let locales: [&dyn Locale; 2] = [&Digits, &German];
+    let spelled: Vec<String> = locales
+        .iter()
+        .map(|locale| Clock::new(9, 5).spelled(*locale).to_string())
+        .collect();
+    assert_eq!(spelled, ["9h05", "fünf nach neun"]);
+}
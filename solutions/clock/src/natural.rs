@@ -0,0 +1,379 @@
+//! English phrases like `quarter past nine`, `half seven` (British for
+//! 7:30), `ten to midnight`, `nine thirty pm` or `seven o'clock in the
+//! evening`.
+
+use crate::{Clock, Component, ParseClockError, ParseClockErrorKind};
+
+pub(crate) const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+pub(crate) const TENS: [&str; 6] = ["", "", "twenty", "thirty", "forty", "fifty"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hour {
+    Number(i32),
+    Noon,
+    Midnight,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    Past,
+    To,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Meridiem {
+    Am,
+    Pm,
+    /// `at night`: evening hours for 6 to 11, otherwise early morning.
+    Night,
+}
+
+struct Parser {
+    /// Lowercase words without dots, with their byte offset in the input.
+    words: Vec<(usize, String)>,
+    at: usize,
+    len: usize,
+}
+
+impl Parser {
+    fn new(input: &str) -> Self {
+        let mut words = Vec::new();
+        let mut start = None;
+        for (at, c) in input.char_indices().chain([(input.len(), ' ')]) {
+            match (start, c.is_whitespace() || c == '-') {
+                (None, false) => start = Some(at),
+                (Some(from), true) => {
+                    let word: String = input[from..at]
+                        .trim_end_matches([',', '!', '?'])
+                        .chars()
+                        .filter(|&c| c != '.')
+                        .flat_map(char::to_lowercase)
+                        .collect();
+                    if !word.is_empty() {
+                        words.push((from, word));
+                    }
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        Self {
+            words,
+            at: 0,
+            len: input.len(),
+        }
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.words.get(self.at).map(|(_, word)| word.as_str())
+    }
+
+    fn peek_at(&self, ahead: usize) -> Option<&str> {
+        self.words
+            .get(self.at + ahead)
+            .map(|(_, word)| word.as_str())
+    }
+
+    fn position(&self) -> usize {
+        self.words.get(self.at).map_or(self.len, |(at, _)| *at)
+    }
+
+    fn eat(&mut self, word: &str) -> bool {
+        let found = self.peek() == Some(word);
+        if found {
+            self.at += 1;
+        }
+        found
+    }
+
+    fn error(&self, kind: ParseClockErrorKind) -> ParseClockError {
+        ParseClockError::new(kind, self.position())
+    }
+
+    /// A number from digits or words up to `fifty-nine`.
+    fn number(&mut self) -> Option<i32> {
+        let word = self.peek()?;
+        if !word.is_empty() && word.bytes().all(|b| b.is_ascii_digit()) {
+            let number = word.parse().unwrap_or(i32::MAX);
+            self.at += 1;
+            return Some(number);
+        }
+        if let Some(unit) = UNITS.iter().position(|unit| *unit == word) {
+            self.at += 1;
+            return Some(unit as i32);
+        }
+        let tens = TENS
+            .iter()
+            .position(|tens| !tens.is_empty() && *tens == word)?;
+        self.at += 1;
+        let unit = match self.peek() {
+            Some(word) => UNITS[1..10].iter().position(|unit| *unit == word),
+            None => None,
+        };
+        if unit.is_some() {
+            self.at += 1;
+        }
+        Some(tens as i32 * 10 + unit.map_or(0, |unit| unit as i32 + 1))
+    }
+
+    fn hour(&mut self) -> Result<Hour, ParseClockError> {
+        match self.peek() {
+            None => Err(self.error(ParseClockErrorKind::MissingComponent(Component::Hour))),
+            Some("noon" | "midday") => {
+                self.at += 1;
+                Ok(Hour::Noon)
+            }
+            Some("midnight") => {
+                self.at += 1;
+                Ok(Hour::Midnight)
+            }
+            Some(_) => {
+                let position = self.position();
+                match self.number() {
+                    Some(hour) if hour <= 23 => Ok(Hour::Number(hour)),
+                    Some(_) => Err(ParseClockError::new(
+                        ParseClockErrorKind::OutOfRange(Component::Hour),
+                        position,
+                    )),
+                    None => Err(self.error(ParseClockErrorKind::NotANumber(Component::Hour))),
+                }
+            }
+        }
+    }
+
+    /// `quarter`, `a quarter`, `half` or a number of minutes, if followed by
+    /// `past` or `to`.
+    fn relative_minutes(&mut self) -> Result<Option<(i32, Relation)>, ParseClockError> {
+        let start = self.at;
+        let position = self.position();
+        self.eat("a");
+        let minutes = match self.peek() {
+            Some("quarter") => {
+                self.at += 1;
+                Some(15)
+            }
+            Some("half") => {
+                self.at += 1;
+                Some(30)
+            }
+            _ => self.number(),
+        };
+        let Some(minutes) = minutes else {
+            self.at = start;
+            return Ok(None);
+        };
+        if !self.eat("minutes") {
+            self.eat("minute");
+        }
+        let relation = match self.peek() {
+            Some("past" | "after") => Relation::Past,
+            Some("to" | "before" | "till" | "til") => Relation::To,
+            _ => {
+                self.at = start;
+                return Ok(None);
+            }
+        };
+        self.at += 1;
+        if !(1..=59).contains(&minutes) {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::OutOfRange(Component::Minute),
+                position,
+            ));
+        }
+        Ok(Some((minutes, relation)))
+    }
+
+    fn meridiem(&mut self) -> Option<Meridiem> {
+        let meridiem = match (self.peek()?, self.peek_at(1), self.peek_at(2)) {
+            ("am", ..) => (Meridiem::Am, 1),
+            ("pm", ..) => (Meridiem::Pm, 1),
+            ("in", Some("the"), Some("morning")) => (Meridiem::Am, 3),
+            ("in", Some("the"), Some("afternoon" | "evening")) => (Meridiem::Pm, 3),
+            ("at", Some("night"), _) => (Meridiem::Night, 2),
+            _ => return None,
+        };
+        self.at += meridiem.1;
+        Some(meridiem.0)
+    }
+
+    fn parse(&mut self) -> Result<Clock, ParseClockError> {
+        if self.words.is_empty() {
+            return Err(ParseClockError::new(ParseClockErrorKind::Empty, 0));
+        }
+        let (minutes, relation, hour_at, hour) = if self.peek() == Some("half")
+            && !matches!(self.peek_at(1), Some("past" | "after" | "to"))
+        {
+            // British `half seven` is half past seven.
+            self.at += 1;
+            (30, Relation::Past, self.position(), self.hour()?)
+        } else if let Some((minutes, relation)) = self.relative_minutes()? {
+            (minutes, relation, self.position(), self.hour()?)
+        } else {
+            let hour_at = self.position();
+            let hour = self.hour()?;
+            let minutes = if self.eat("o'clock") || self.eat("oclock") {
+                0
+            } else if matches!(hour, Hour::Number(_)) {
+                // `nine thirty` or `ten oh five`.
+                let position = self.position();
+                let oh = self.eat("oh") || self.eat("o");
+                match self.number() {
+                    Some(minutes) if minutes <= 59 && (!oh || minutes <= 9) => minutes,
+                    Some(_) => {
+                        return Err(ParseClockError::new(
+                            ParseClockErrorKind::OutOfRange(Component::Minute),
+                            position,
+                        ))
+                    }
+                    None if oh => {
+                        return Err(self.error(ParseClockErrorKind::NotANumber(Component::Minute)))
+                    }
+                    None => 0,
+                }
+            } else {
+                0
+            };
+            (minutes, Relation::Past, hour_at, hour)
+        };
+        let meridiem_at = self.position();
+        let meridiem = self.meridiem();
+        if self.at < self.words.len() {
+            return Err(self.error(ParseClockErrorKind::TrailingInput));
+        }
+        let hours = match (hour, meridiem) {
+            (Hour::Noon | Hour::Midnight, Some(_)) => {
+                return Err(ParseClockError::new(
+                    ParseClockErrorKind::InvalidMeridiem,
+                    meridiem_at,
+                ))
+            }
+            (Hour::Noon, None) => 12,
+            (Hour::Midnight, None) => 24,
+            (Hour::Number(hours), None) => hours,
+            (Hour::Number(hours), Some(_)) if hours > 12 => {
+                return Err(ParseClockError::new(
+                    ParseClockErrorKind::OutOfRange(Component::Hour),
+                    hour_at,
+                ))
+            }
+            (Hour::Number(hours), Some(Meridiem::Am)) => hours % 12,
+            (Hour::Number(hours), Some(Meridiem::Pm)) => hours % 12 + 12,
+            (Hour::Number(hours), Some(Meridiem::Night)) if (6..12).contains(&hours) => hours + 12,
+            (Hour::Number(hours), Some(Meridiem::Night)) => hours % 12,
+        };
+        Ok(match relation {
+            Relation::Past => Clock::new(hours, minutes),
+            Relation::To => Clock::new(hours, -minutes),
+        })
+    }
+}
+
+impl Clock {
+    /// Parses an English phrase like `quarter past nine`, `half seven`,
+    /// `noon` or `10 to midnight`. Hours without `am`/`pm` or `in the
+    /// evening` are read as 24 hour values, so `nine` is 09:00.
+    pub fn parse_natural(input: &str) -> Result<Clock, ParseClockError> {
+        Parser::new(input).parse()
+    }
+}
+
+#[test]
+fn test_relative_phrases() {
+    let parse = |s| Clock::parse_natural(s).unwrap();
+    assert_eq!(parse("quarter past nine"), Clock::new(9, 15));
+    assert_eq!(parse("a quarter to noon"), Clock::new(11, 45));
+    assert_eq!(parse("half past seven"), Clock::new(7, 30));
+    assert_eq!(parse("10 to midnight"), Clock::new(23, 50));
+    assert_eq!(parse("five past midnight"), Clock::new(0, 5));
+    assert_eq!(parse("quarter to one"), Clock::new(0, 45));
+    assert_eq!(
+        parse("Twenty-five minutes past ten p.m."),
+        Clock::new(22, 25)
+    );
+}
+
+#[test]
+fn test_british_half() {
+    assert_eq!(
+        Clock::parse_natural("half seven").unwrap(),
+        Clock::new(7, 30)
+    );
+    assert_eq!(
+        Clock::parse_natural("half seven in the evening").unwrap(),
+        Clock::new(19, 30)
+    );
+}
+
+#[test]
+fn test_plain_hours_and_minutes() {
+    let parse = |s| Clock::parse_natural(s).unwrap();
+    assert_eq!(parse("Noon"), Clock::new(12, 0));
+    assert_eq!(parse("midnight"), Clock::new(0, 0));
+    assert_eq!(parse("nine"), Clock::new(9, 0));
+    assert_eq!(parse("nine thirty"), Clock::new(9, 30));
+    assert_eq!(parse("ten oh five"), Clock::new(10, 5));
+    assert_eq!(parse("seven o'clock in the evening"), Clock::new(19, 0));
+    assert_eq!(parse("eleven at night"), Clock::new(23, 0));
+    assert_eq!(parse("two at night"), Clock::new(2, 0));
+    assert_eq!(parse("twelve am"), Clock::new(0, 0));
+    assert_eq!(parse("twelve pm"), Clock::new(12, 0));
+    assert_eq!(parse("18 45"), Clock::new(18, 45));
+}
+
+#[test]
+fn test_natural_errors() {
+    let kind = |s| Clock::parse_natural(s).unwrap_err().kind();
+    let position = |s| Clock::parse_natural(s).unwrap_err().position();
+    assert_eq!(kind("  "), ParseClockErrorKind::Empty);
+    assert_eq!(
+        kind("quarter past"),
+        ParseClockErrorKind::MissingComponent(Component::Hour)
+    );
+    assert_eq!(position("quarter past"), 12);
+    assert_eq!(
+        kind("quarter past banana"),
+        ParseClockErrorKind::NotANumber(Component::Hour)
+    );
+    assert_eq!(position("quarter past banana"), 13);
+    assert_eq!(
+        kind("sixty past nine"),
+        ParseClockErrorKind::NotANumber(Component::Hour)
+    );
+    assert_eq!(
+        kind("75 past nine"),
+        ParseClockErrorKind::OutOfRange(Component::Minute)
+    );
+    assert_eq!(
+        kind("thirteen pm"),
+        ParseClockErrorKind::OutOfRange(Component::Hour)
+    );
+    assert_eq!(kind("noon pm"), ParseClockErrorKind::InvalidMeridiem);
+    assert_eq!(position("noon pm"), 5);
+    assert_eq!(
+        kind("nine thirty please"),
+        ParseClockErrorKind::TrailingInput
+    );
+    assert_eq!(position("nine thirty please"), 12);
+}
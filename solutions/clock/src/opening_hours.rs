@@ -0,0 +1,449 @@
+//! A subset of the OpenStreetMap
+//! [`opening_hours`](https://wiki.openstreetmap.org/wiki/Key:opening_hours)
+//! syntax: rules separated by `;`, each with optional weekday selectors
+//! (`Mo-Fr`, `Sa,Su`, `PH`) followed by time ranges, `off`/`closed`, `open`
+//! or `24/7`. A later rule replaces the times of the days it selects.
+
+use std::{fmt, str::FromStr};
+
+use crate::{Clock, ClockRange, ParseClockError, Weekday, NANOS_PER_DAY};
+
+const NANOS_PER_WEEK: i64 = 7 * NANOS_PER_DAY;
+
+const ABBREVIATIONS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+fn weekday_from_abbreviation(s: &str) -> Option<Weekday> {
+    ABBREVIATIONS
+        .iter()
+        .position(|abbreviation| *abbreviation == s)
+        .map(|idx| Weekday::ALL[idx])
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OpeningHours {
+    days: [Vec<ClockRange>; 7],
+    public_holiday: Option<Vec<ClockRange>>,
+}
+
+impl OpeningHours {
+    /// The ranges starting on `weekday`. A range like `22:00-02:00` keeps the
+    /// place open into the next day.
+    pub fn ranges(&self, weekday: Weekday) -> &[ClockRange] {
+        &self.days[weekday as usize]
+    }
+
+    /// The ranges of a `PH` rule, `None` if public holidays are not special.
+    pub fn public_holiday(&self) -> Option<&[ClockRange]> {
+        self.public_holiday.as_deref()
+    }
+
+    pub fn is_open(&self, weekday: Weekday, clock: Clock) -> bool {
+        let at = instant(weekday, clock);
+        self.week()
+            .iter()
+            .any(|&(start, end)| start <= at && at < end)
+    }
+
+    /// The first moment strictly after `weekday` at `clock` where the place
+    /// goes from closed to open, `None` if that never happens.
+    pub fn next_open(&self, weekday: Weekday, clock: Clock) -> Option<(Weekday, Clock)> {
+        let week = self.week();
+        let wraps = week.first().is_some_and(|first| first.0 == 0)
+            && week.last().is_some_and(|last| last.1 == NANOS_PER_WEEK);
+        let openings = week.iter().map(|&(start, _)| start).skip(wraps as usize);
+        next_after(openings, instant(weekday, clock))
+    }
+
+    /// The first moment strictly after `weekday` at `clock` where the place
+    /// goes from open to closed, `None` if that never happens.
+    pub fn next_close(&self, weekday: Weekday, clock: Clock) -> Option<(Weekday, Clock)> {
+        let week = self.week();
+        let wraps = week.first().is_some_and(|first| first.0 == 0)
+            && week.last().is_some_and(|last| last.1 == NANOS_PER_WEEK);
+        let mut closings: Vec<i64> = week.iter().map(|&(_, end)| end).collect();
+        if wraps {
+            closings.pop();
+        }
+        next_after(closings, instant(weekday, clock))
+    }
+
+    /// The open intervals in nanos since Monday midnight, sorted and merged.
+    /// Intervals running past Sunday midnight continue on Monday.
+    fn week(&self) -> Vec<(i64, i64)> {
+        let mut intervals = Vec::new();
+        for (day, ranges) in self.days.iter().enumerate() {
+            for range in ranges {
+                let start = day as i64 * NANOS_PER_DAY + range.start().nanos_since_midnight();
+                let end = start + range.duration().as_nanos();
+                if end > NANOS_PER_WEEK {
+                    intervals.push((start, NANOS_PER_WEEK));
+                    intervals.push((0, end - NANOS_PER_WEEK));
+                } else {
+                    intervals.push((start, end));
+                }
+            }
+        }
+        intervals.sort_unstable();
+        let mut merged: Vec<(i64, i64)> = Vec::new();
+        for (start, end) in intervals {
+            if start >= end {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+}
+
+fn instant(weekday: Weekday, clock: Clock) -> i64 {
+    weekday as i64 * NANOS_PER_DAY + clock.nanos_since_midnight()
+}
+
+/// The first of the sorted `instants` after `at`, wrapping to the next week.
+fn next_after(instants: impl IntoIterator<Item = i64>, at: i64) -> Option<(Weekday, Clock)> {
+    let instants: Vec<i64> = instants.into_iter().collect();
+    let next = instants
+        .iter()
+        .find(|&&instant| instant > at)
+        .or(instants.first())?
+        .rem_euclid(NANOS_PER_WEEK);
+    Some((
+        Weekday::ALL[(next / NANOS_PER_DAY) as usize],
+        Clock::from_nanos_since_midnight(next % NANOS_PER_DAY),
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseOpeningHoursError {
+    Empty,
+    /// Not a weekday like `Mo`, a range like `Mo-Fr`, or `PH`.
+    UnknownSelector(usize),
+    /// A selector, time range or state where it is not allowed.
+    Unexpected(usize),
+    /// Valid syntax this parser does not evaluate, like `12:00-13:00 off`.
+    Unsupported(usize),
+    Time(ParseClockError),
+}
+
+impl ParseOpeningHoursError {
+    /// The byte offset into the input where the problem was found.
+    pub fn position(&self) -> usize {
+        match self {
+            ParseOpeningHoursError::Empty => 0,
+            ParseOpeningHoursError::UnknownSelector(position)
+            | ParseOpeningHoursError::Unexpected(position)
+            | ParseOpeningHoursError::Unsupported(position) => *position,
+            ParseOpeningHoursError::Time(err) => err.position(),
+        }
+    }
+}
+
+impl fmt::Display for ParseOpeningHoursError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseOpeningHoursError::Empty => {
+                write!(f, "Error parse string to OpeningHours: empty input")
+            }
+            ParseOpeningHoursError::UnknownSelector(position) => write!(
+                f,
+                "Error parse string to OpeningHours: unknown selector at byte {}",
+                position
+            ),
+            ParseOpeningHoursError::Unexpected(position) => write!(
+                f,
+                "Error parse string to OpeningHours: unexpected token at byte {}",
+                position
+            ),
+            ParseOpeningHoursError::Unsupported(position) => write!(
+                f,
+                "Error parse string to OpeningHours: unsupported rule at byte {}",
+                position
+            ),
+            ParseOpeningHoursError::Time(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParseOpeningHoursError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseOpeningHoursError::Time(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Splits `s` on ASCII whitespace and commas, with the byte offset of every
+/// piece.
+fn pieces(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut at = 0;
+    s.split(|c: char| c.is_ascii_whitespace() || c == ',')
+        .map(move |piece| {
+            let start = at;
+            at += piece.len() + 1;
+            (start, piece)
+        })
+        .filter(|(_, piece)| !piece.is_empty())
+}
+
+#[derive(Default)]
+struct Selector {
+    days: [bool; 7],
+    public_holiday: bool,
+}
+
+impl Selector {
+    fn add(&mut self, piece: &str, position: usize) -> Result<(), ParseOpeningHoursError> {
+        if piece == "PH" {
+            self.public_holiday = true;
+            return Ok(());
+        }
+        let (first, last) = piece.split_once('-').unwrap_or((piece, piece));
+        let (Some(first), Some(last)) = (
+            weekday_from_abbreviation(first),
+            weekday_from_abbreviation(last),
+        ) else {
+            return Err(ParseOpeningHoursError::UnknownSelector(position));
+        };
+        let mut day = first;
+        loop {
+            self.days[day as usize] = true;
+            if day == last {
+                return Ok(());
+            }
+            day = day.succ();
+        }
+    }
+}
+
+impl OpeningHours {
+    fn apply_rule(&mut self, rule: &str, at: usize) -> Result<(), ParseOpeningHoursError> {
+        let mut selector: Option<Selector> = None;
+        let mut ranges = Vec::new();
+        let mut open = None;
+        for (offset, piece) in pieces(rule) {
+            let position = at + offset;
+            if open.is_some() {
+                return Err(ParseOpeningHoursError::Unexpected(position));
+            }
+            if piece == "24/7" {
+                ranges.push(ClockRange::full_day());
+            } else if piece.starts_with(|c: char| c.is_ascii_digit()) {
+                let range = piece.parse().map_err(|err: ParseClockError| {
+                    ParseOpeningHoursError::Time(err.offset(position))
+                })?;
+                ranges.push(range);
+            } else if matches!(piece, "off" | "closed" | "open") {
+                open = Some(piece == "open");
+            } else if ranges.is_empty() {
+                selector
+                    .get_or_insert_with(Selector::default)
+                    .add(piece, position)?;
+            } else {
+                return Err(ParseOpeningHoursError::Unexpected(position));
+            }
+        }
+        match open {
+            Some(false) if !ranges.is_empty() => {
+                return Err(ParseOpeningHoursError::Unsupported(
+                    at + rule.len() - rule.trim_start().len(),
+                ))
+            }
+            Some(false) => {}
+            // A bare selector like `Mo-Fr` means open all day.
+            _ if ranges.is_empty() => ranges.push(ClockRange::full_day()),
+            _ => {}
+        }
+        let selector = selector.unwrap_or(Selector {
+            days: [true; 7],
+            public_holiday: false,
+        });
+        for (day, selected) in selector.days.into_iter().enumerate() {
+            if selected {
+                self.days[day] = ranges.clone();
+            }
+        }
+        if selector.public_holiday {
+            self.public_holiday = Some(ranges);
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for OpeningHours {
+    type Err = ParseOpeningHoursError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseOpeningHoursError::Empty);
+        }
+        let mut hours = OpeningHours::default();
+        let mut at = 0;
+        for rule in s.split(';') {
+            if !rule.trim().is_empty() {
+                hours.apply_rule(rule, at)?;
+            }
+            at += rule.len() + 1;
+        }
+        Ok(hours)
+    }
+}
+
+fn write_ranges(f: &mut fmt::Formatter<'_>, ranges: &[ClockRange]) -> fmt::Result {
+    if ranges.is_empty() {
+        return write!(f, "off");
+    }
+    for (idx, range) in ranges.iter().enumerate() {
+        if idx > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{}", range)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for OpeningHours {
+    /// Writes one rule per run of consecutive days with the same ranges,
+    /// leaving out closed days.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self
+            .days
+            .iter()
+            .all(|ranges| ranges == &[ClockRange::full_day()])
+            && self.public_holiday.is_none()
+        {
+            return write!(f, "24/7");
+        }
+        let mut rules = 0;
+        let mut day = 0;
+        while day < 7 {
+            let run = self.days[day..]
+                .iter()
+                .take_while(|ranges| *ranges == &self.days[day])
+                .count();
+            if !self.days[day].is_empty() {
+                if rules > 0 {
+                    write!(f, "; ")?;
+                }
+                write!(f, "{}", ABBREVIATIONS[day])?;
+                if run > 1 {
+                    write!(f, "-{}", ABBREVIATIONS[day + run - 1])?;
+                }
+                write!(f, " ")?;
+                write_ranges(f, &self.days[day])?;
+                rules += 1;
+            }
+            day += run;
+        }
+        if let Some(ranges) = &self.public_holiday {
+            if rules > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "PH ")?;
+            write_ranges(f, ranges)?;
+        } else if rules == 0 {
+            write!(f, "off")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_weekdays_and_public_holidays() {
+    let hours: OpeningHours = "Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off".parse().unwrap();
+    assert!(hours.is_open(Weekday::Monday, Clock::new(12, 0)));
+    assert!(hours.is_open(Weekday::Saturday, Clock::new(9, 0)));
+    assert!(!hours.is_open(Weekday::Saturday, Clock::new(13, 0)));
+    assert!(!hours.is_open(Weekday::Sunday, Clock::new(12, 0)));
+    assert_eq!(hours.ranges(Weekday::Sunday), &[]);
+    assert_eq!(hours.public_holiday(), Some(&[][..]));
+    assert_eq!(
+        hours.next_open(Weekday::Saturday, Clock::new(14, 0)),
+        Some((Weekday::Monday, Clock::new(8, 0)))
+    );
+    assert_eq!(
+        hours.next_close(Weekday::Friday, Clock::new(17, 0)),
+        Some((Weekday::Friday, Clock::new(18, 0)))
+    );
+    assert_eq!(
+        hours.to_string(),
+        "Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off"
+    );
+}
+
+#[test]
+fn test_later_rules_override_and_lists() {
+    let hours: OpeningHours = "Mo-Sa 10:00-12:00, 13:00-20:00; Sa 10:00-14:00; Tu off"
+        .parse()
+        .unwrap();
+    assert_eq!(hours.ranges(Weekday::Monday).len(), 2);
+    assert!(!hours.is_open(Weekday::Monday, Clock::new(12, 30)));
+    assert!(hours.is_open(Weekday::Saturday, Clock::new(13, 30)));
+    assert!(!hours.is_open(Weekday::Tuesday, Clock::new(11, 0)));
+    assert_eq!(
+        hours.next_open(Weekday::Monday, Clock::new(20, 0)),
+        Some((Weekday::Wednesday, Clock::new(10, 0)))
+    );
+    assert_eq!(
+        hours.to_string(),
+        "Mo 10:00-12:00,13:00-20:00; We-Fr 10:00-12:00,13:00-20:00; Sa 10:00-14:00"
+    );
+}
+
+#[test]
+fn test_ranges_past_midnight() {
+    let hours: OpeningHours = "Fr,Sa 22:00-02:00; Su 20:00-24:00".parse().unwrap();
+    assert!(hours.is_open(Weekday::Saturday, Clock::new(1, 0)));
+    assert!(hours.is_open(Weekday::Sunday, Clock::new(1, 59)));
+    assert!(!hours.is_open(Weekday::Sunday, Clock::new(2, 0)));
+    assert!(!hours.is_open(Weekday::Monday, Clock::new(0, 0)));
+    assert_eq!(
+        hours.next_close(Weekday::Friday, Clock::new(23, 0)),
+        Some((Weekday::Saturday, Clock::new(2, 0)))
+    );
+    assert_eq!(
+        hours.next_close(Weekday::Sunday, Clock::new(21, 0)),
+        Some((Weekday::Monday, Clock::new(0, 0)))
+    );
+}
+
+#[test]
+fn test_always_open() {
+    let hours: OpeningHours = "24/7".parse().unwrap();
+    assert!(hours.is_open(Weekday::Sunday, Clock::new(3, 0)));
+    assert_eq!(hours.next_open(Weekday::Sunday, Clock::new(3, 0)), None);
+    assert_eq!(hours.next_close(Weekday::Sunday, Clock::new(3, 0)), None);
+    assert_eq!(hours.to_string(), "24/7");
+    let weekdays: OpeningHours = "Mo-Fr".parse().unwrap();
+    assert!(weekdays.is_open(Weekday::Friday, Clock::new(23, 59)));
+    assert_eq!(
+        weekdays.next_close(Weekday::Monday, Clock::new(8, 0)),
+        Some((Weekday::Saturday, Clock::new(0, 0)))
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = "Mo-Xy 10:00-12:00".parse::<OpeningHours>().unwrap_err();
+    assert_eq!(err, ParseOpeningHoursError::UnknownSelector(0));
+    let err = "Mo 10:00-1x:00".parse::<OpeningHours>().unwrap_err();
+    assert!(matches!(err, ParseOpeningHoursError::Time(_)));
+    assert_eq!(err.position(), 9);
+    assert_eq!(
+        "Mo 10:00-12:00 Tu".parse::<OpeningHours>().unwrap_err(),
+        ParseOpeningHoursError::Unexpected(15)
+    );
+    assert_eq!(
+        "Mo-Fr 08:00-18:00; Mo 12:00-13:00 off"
+            .parse::<OpeningHours>()
+            .unwrap_err(),
+        ParseOpeningHoursError::Unsupported(19)
+    );
+    assert_eq!(
+        " ".parse::<OpeningHours>().unwrap_err(),
+        ParseOpeningHoursError::Empty
+    );
+}
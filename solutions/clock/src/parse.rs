@@ -0,0 +1,329 @@
+use crate::{
+    format::{Item, Items, Numeric},
+    Clock, Component, ParseClockError, ParseClockErrorKind,
+};
+
+/// The patterns [`Clock::parse_lenient`] tries, in order.
+pub const LENIENT_PATTERNS: &[&str] = &[
+    "%H:%M:%S.%f",
+    "%H:%M:%S",
+    "%H:%M",
+    "%I:%M:%S %p",
+    "%I:%M %p",
+    "%I.%M %p",
+    "%I %p",
+    "%Hh%M",
+    "%Hh",
+    "%H.%M",
+    "%H%M",
+];
+
+#[derive(Default)]
+struct Parsed {
+    hours: Option<i32>,
+    hours12: Option<i32>,
+    pm: Option<bool>,
+    minutes: i32,
+    seconds: i32,
+    nanos: i32,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    rest: &'a str,
+    parsed: Parsed,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            rest: input,
+            parsed: Parsed::default(),
+        }
+    }
+
+    fn position(&self) -> usize {
+        self.input.len() - self.rest.len()
+    }
+
+    fn error(&self, kind: ParseClockErrorKind) -> ParseClockError {
+        ParseClockError::new(kind, self.position())
+    }
+
+    fn digits(
+        &mut self,
+        component: Component,
+        max: usize,
+    ) -> Result<(i32, usize), ParseClockError> {
+        let len = self
+            .rest
+            .bytes()
+            .take(max)
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if len == 0 {
+            return Err(self.error(ParseClockErrorKind::NotANumber(component)));
+        }
+        let position = self.position();
+        let (digits, rest) = self.rest.split_at(len);
+        let value = digits
+            .parse()
+            .map_err(|err| ParseClockError::from_int(component, position, err))?;
+        self.rest = rest;
+        Ok((value, len))
+    }
+
+    fn items(&mut self, pattern: &str) -> Result<(), ParseClockError> {
+        for item in Items::new(pattern) {
+            match item {
+                Item::Literal(literal) => self.literal(literal)?,
+                Item::Numeric(kind, _) => self.numeric(kind)?,
+                Item::Fraction(digits) => {
+                    let (fraction, len) = self.digits(Component::Fraction, digits)?;
+                    self.parsed.nanos = fraction * 10_i32.pow(9 - len as u32);
+                }
+                Item::AmPm { .. } => self.meridiem()?,
+                Item::Composite(pattern) => self.items(pattern)?,
+                Item::Unknown(_) => return Err(self.error(ParseClockErrorKind::InvalidPattern)),
+            }
+        }
+        Ok(())
+    }
+
+    fn literal(&mut self, literal: &str) -> Result<(), ParseClockError> {
+        for c in literal.chars() {
+            if c.is_whitespace() {
+                self.rest = self.rest.trim_start();
+            } else {
+                self.rest = self
+                    .rest
+                    .strip_prefix(c)
+                    .ok_or_else(|| self.error(ParseClockErrorKind::MissingSeparator))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn numeric(&mut self, kind: Numeric) -> Result<(), ParseClockError> {
+        self.rest = self.rest.trim_start_matches(' ');
+        let (component, range) = match kind {
+            Numeric::Hour => (Component::Hour, 0..=23),
+            Numeric::Hour12 => (Component::Hour, 1..=12),
+            Numeric::Minute => (Component::Minute, 0..=59),
+            Numeric::Second => (Component::Second, 0..=59),
+        };
+        let position = self.position();
+        let (value, _) = self.digits(component, 2)?;
+        if !range.contains(&value) {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::OutOfRange(component),
+                position,
+            ));
+        }
+        match kind {
+            Numeric::Hour => self.parsed.hours = Some(value),
+            Numeric::Hour12 => self.parsed.hours12 = Some(value),
+            Numeric::Minute => self.parsed.minutes = value,
+            Numeric::Second => self.parsed.seconds = value,
+        }
+        Ok(())
+    }
+
+    fn meridiem(&mut self) -> Result<(), ParseClockError> {
+        let dotted = self.rest.get(..4).map(str::to_ascii_lowercase);
+        let marker = self.rest.get(..2).map(str::to_ascii_lowercase);
+        let (pm, len) = match (dotted.as_deref(), marker.as_deref()) {
+            (Some("a.m."), _) => (false, 4),
+            (Some("p.m."), _) => (true, 4),
+            (_, Some("am")) => (false, 2),
+            (_, Some("pm")) => (true, 2),
+            _ => return Err(self.error(ParseClockErrorKind::InvalidMeridiem)),
+        };
+        self.parsed.pm = Some(pm);
+        self.rest = &self.rest[len..];
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Clock, ParseClockError> {
+        if !self.rest.is_empty() {
+            return Err(self.error(ParseClockErrorKind::TrailingInput));
+        }
+        let parsed = self.parsed;
+        let hours = match (parsed.hours12, parsed.pm, parsed.hours) {
+            (Some(hours), Some(pm), _) => hours % 12 + if pm { 12 } else { 0 },
+            (Some(hours), None, _) | (None, _, Some(hours)) => hours,
+            (None, _, None) => {
+                return Err(ParseClockError::new(
+                    ParseClockErrorKind::MissingComponent(Component::Hour),
+                    0,
+                ))
+            }
+        };
+        Ok(Clock::with_nanos(
+            hours,
+            parsed.minutes,
+            parsed.seconds,
+            parsed.nanos,
+        ))
+    }
+}
+
+impl Clock {
+    /// Parses `input` with the directives understood by [`Clock::format`].
+    /// Numbers take one or two digits, whitespace in the pattern matches any
+    /// amount of whitespace and `%p` ignores case. Components are validated
+    /// rather than wrapped around.
+    pub fn parse_with(input: &str, pattern: &str) -> Result<Clock, ParseClockError> {
+        if input.is_empty() {
+            return Err(ParseClockError::new(ParseClockErrorKind::Empty, 0));
+        }
+        let mut parser = Parser::new(input);
+        parser.items(pattern)?;
+        parser.finish()
+    }
+
+    /// Tries each of `patterns` in turn and returns the first match. If none
+    /// matches, the error of the pattern that got furthest is returned.
+    pub fn parse_any(input: &str, patterns: &[&str]) -> Result<Clock, ParseClockError> {
+        let trimmed = input.trim_start();
+        let offset = input.len() - trimmed.len();
+        let mut furthest = ParseClockError::new(ParseClockErrorKind::Empty, 0);
+        for pattern in patterns {
+            match Clock::parse_with(trimmed.trim_end(), pattern) {
+                Ok(clock) => return Ok(clock),
+                Err(err) if err.position() >= furthest.position() => furthest = err,
+                Err(_) => {}
+            }
+        }
+        Err(furthest.offset(offset))
+    }
+
+    /// Accepts the common spellings in [`LENIENT_PATTERNS`], such as `9.30 pm`,
+    /// `2130` or `21h30`.
+    pub fn parse_lenient(input: &str) -> Result<Clock, ParseClockError> {
+        Clock::parse_any(input, LENIENT_PATTERNS)
+    }
+}
+
+#[test]
+fn test_parse_with_24h() {
+    assert_eq!(
+        Clock::parse_with("21:30", "%H:%M").unwrap(),
+        Clock::new(21, 30)
+    );
+    assert_eq!(
+        Clock::parse_with("9:05:07", "%T").unwrap(),
+        Clock::with_seconds(9, 5, 7)
+    );
+    assert_eq!(
+        Clock::parse_with("2130", "%H%M").unwrap(),
+        Clock::new(21, 30)
+    );
+}
+
+#[test]
+fn test_parse_with_12h() {
+    assert_eq!(
+        Clock::parse_with("12:15 AM", "%I:%M %p").unwrap(),
+        Clock::new(0, 15)
+    );
+    assert_eq!(
+        Clock::parse_with("12:15 pm", "%I:%M %p").unwrap(),
+        Clock::new(12, 15)
+    );
+    assert_eq!(
+        Clock::parse_with("9.30PM", "%I.%M %p").unwrap(),
+        Clock::new(21, 30)
+    );
+}
+
+#[test]
+fn test_parse_with_fraction() {
+    assert_eq!(
+        Clock::parse_with("12:30:15.25", "%T.%f").unwrap(),
+        Clock::with_nanos(12, 30, 15, 250_000_000)
+    );
+    assert!(Clock::parse_with("12:30:15.2500", "%T.%3f").is_err());
+}
+
+#[test]
+fn test_parse_with_rejects_bad_input() {
+    assert!(Clock::parse_with("24:00", "%H:%M").is_err());
+    assert!(Clock::parse_with("13:00 pm", "%I:%M %p").is_err());
+    assert!(Clock::parse_with("12:60", "%H:%M").is_err());
+    assert!(Clock::parse_with("12:30 extra", "%H:%M").is_err());
+    assert!(Clock::parse_with("12-30", "%H:%M").is_err());
+    assert!(Clock::parse_with("12:30", "%H:%Q").is_err());
+    assert!(Clock::parse_with("30", "%M").is_err());
+}
+
+#[test]
+fn test_format_parse_round_trip() {
+    let patterns = ["%H:%M", "%I:%M %p", "%-I.%M%P", "%r", "%T.%6f", "%Hh%M"];
+    for pattern in patterns {
+        for clock in [Clock::new(0, 0), Clock::with_nanos(12, 7, 9, 123_456_000)] {
+            let text = clock.format(pattern).to_string();
+            let parsed = Clock::parse_with(&text, pattern).unwrap();
+            assert_eq!(parsed.format(pattern).to_string(), text);
+        }
+    }
+}
+
+#[test]
+fn test_parse_lenient() {
+    let cases = [
+        ("9.30 pm", Clock::new(21, 30)),
+        ("2130", Clock::new(21, 30)),
+        ("21h30", Clock::new(21, 30)),
+        ("21h", Clock::new(21, 0)),
+        (" 9:30 ", Clock::new(9, 30)),
+        ("9 AM", Clock::new(9, 0)),
+        ("12:30:15.5", Clock::with_nanos(12, 30, 15, 500_000_000)),
+        ("7:45:10 pm", Clock::with_seconds(19, 45, 10)),
+        ("12.05 a.m.", Clock::new(0, 5)),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(Clock::parse_lenient(input).unwrap(), expected, "{}", input);
+    }
+    assert!(Clock::parse_lenient("half past nine").is_err());
+}
+
+#[test]
+fn test_parse_with_error_kinds() {
+    let err = |input: &str, pattern: &str| {
+        let err = Clock::parse_with(input, pattern).unwrap_err();
+        (err.kind(), err.position())
+    };
+    assert_eq!(
+        err("24:00", "%H:%M"),
+        (ParseClockErrorKind::OutOfRange(Component::Hour), 0)
+    );
+    assert_eq!(
+        err("12-30", "%H:%M"),
+        (ParseClockErrorKind::MissingSeparator, 2)
+    );
+    assert_eq!(
+        err("12:30 extra", "%H:%M"),
+        (ParseClockErrorKind::TrailingInput, 5)
+    );
+    assert_eq!(
+        err("9:30 xm", "%I:%M %p"),
+        (ParseClockErrorKind::InvalidMeridiem, 5)
+    );
+    assert_eq!(
+        err("30", "%M"),
+        (ParseClockErrorKind::MissingComponent(Component::Hour), 0)
+    );
+    assert_eq!(
+        err("12:30", "%H:%Q"),
+        (ParseClockErrorKind::InvalidPattern, 3)
+    );
+}
+
+#[test]
+fn test_parse_lenient_reports_furthest_error() {
+    let err = Clock::parse_lenient("  9:30 xm").unwrap_err();
+    assert_eq!(err.kind(), ParseClockErrorKind::InvalidMeridiem);
+    assert_eq!(err.position(), 7);
+}
@@ -0,0 +1,315 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Clock, ClockDuration, ParseClockError, ParseClockErrorKind, NANOS_PER_DAY};
+
+/// A half-open span `[start, end)` on the 24 hour circle, which may wrap
+/// around midnight like `22:00-02:00`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockRange {
+    start: Clock,
+    length: ClockDuration,
+}
+
+impl ClockRange {
+    /// The range from `start` forward to `end`. Equal clocks give an empty
+    /// range, use [`ClockRange::full_day`] for all 24 hours.
+    pub fn new(start: Clock, end: Clock) -> Self {
+        Self {
+            start,
+            length: end - start,
+        }
+    }
+
+    /// `length` is clamped to `0..=24h`.
+    pub fn from_start(start: Clock, length: ClockDuration) -> Self {
+        Self {
+            start,
+            length: length.clamp(ClockDuration::ZERO, ClockDuration::DAY),
+        }
+    }
+
+    pub fn full_day() -> Self {
+        Self::from_start(Clock::default(), ClockDuration::DAY)
+    }
+
+    pub fn start(&self) -> Clock {
+        self.start
+    }
+
+    pub fn end(&self) -> Clock {
+        self.start + self.length
+    }
+
+    pub fn duration(&self) -> ClockDuration {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == ClockDuration::ZERO
+    }
+
+    pub fn is_full_day(&self) -> bool {
+        self.length == ClockDuration::DAY
+    }
+
+    pub fn wraps_midnight(&self) -> bool {
+        self.start.nanos_since_midnight() + self.length.as_nanos() > NANOS_PER_DAY
+    }
+
+    pub fn contains(&self, clock: Clock) -> bool {
+        clock - self.start < self.length
+    }
+
+    pub fn overlaps(&self, other: &ClockRange) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The parts both ranges cover. Two ranges that both wrap midnight can
+    /// share two separate pieces, e.g. `22:00-06:00` and `04:00-23:00`.
+    pub fn intersection(&self, other: &ClockRange) -> Vec<ClockRange> {
+        let start = self.start.nanos_since_midnight();
+        let end = start + self.length.as_nanos();
+        let mut pieces = Vec::new();
+        for shift in [-NANOS_PER_DAY, 0, NANOS_PER_DAY] {
+            let other_start = other.start.nanos_since_midnight() + shift;
+            let other_end = other_start + other.length.as_nanos();
+            let (piece_start, piece_end) = (start.max(other_start), end.min(other_end));
+            if piece_start < piece_end {
+                pieces.push((piece_start, piece_end));
+            }
+        }
+        pieces.sort_unstable();
+        let mut merged: Vec<(i64, i64)> = Vec::new();
+        for (piece_start, piece_end) in pieces {
+            match merged.last_mut() {
+                Some(last) if last.1 >= piece_start => last.1 = last.1.max(piece_end),
+                _ => merged.push((piece_start, piece_end)),
+            }
+        }
+        let mut pieces = merged;
+        // On a full day the two ends of `self` touch, so pieces at both ends
+        // are really one.
+        if pieces.len() > 1 && self.is_full_day() {
+            let (first, last) = (pieces[0], pieces[pieces.len() - 1]);
+            if first.0 == start && last.1 == end {
+                pieces.pop();
+                pieces[0] = (last.0, first.1 + NANOS_PER_DAY);
+            }
+        }
+        let mut ranges: Vec<ClockRange> = pieces
+            .into_iter()
+            .map(|(piece_start, piece_end)| {
+                ClockRange::from_start(
+                    Clock::from_nanos_since_midnight(piece_start),
+                    ClockDuration::nanoseconds(piece_end - piece_start),
+                )
+            })
+            .collect();
+        ranges.sort_by_key(|range| range.start);
+        ranges
+    }
+
+    /// Every clock in the range, `step` apart, beginning with `start`.
+    pub fn step_by(&self, step: ClockDuration) -> ClockRangeIter {
+        assert!(step > ClockDuration::ZERO, "step must be positive");
+        ClockRangeIter {
+            range: *self,
+            offset: ClockDuration::ZERO,
+            step,
+        }
+    }
+
+    /// Every minute in the range.
+    pub fn iter(&self) -> ClockRangeIter {
+        self.step_by(ClockDuration::minutes(1))
+    }
+}
+
+impl IntoIterator for ClockRange {
+    type Item = Clock;
+    type IntoIter = ClockRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClockRangeIter {
+    range: ClockRange,
+    offset: ClockDuration,
+    step: ClockDuration,
+}
+
+impl Iterator for ClockRangeIter {
+    type Item = Clock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.range.length {
+            return None;
+        }
+        let clock = self.range.start + self.offset;
+        self.offset = self.offset + self.step;
+        Some(clock)
+    }
+}
+
+/// A full day is `00:00-24:00` from midnight and `06:00-06:00+1d` from any
+/// other start, as `06:00-06:00` is empty.
+impl fmt::Display for ClockRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_full_day() && self.start == Clock::default() {
+            write!(f, "00:00-24:00")
+        } else if self.is_full_day() {
+            write!(f, "{0}-{0}+1d", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end())
+        }
+    }
+}
+
+impl FromStr for ClockRange {
+    type Err = ParseClockError;
+
+    /// Parses `start-end` with both sides in the [`Clock`] format. `24:00` as
+    /// end time after `00:00` means the full day, as does `+1d` after an end
+    /// equal to the start.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((start, end)) = s.split_once('-') else {
+            return Err(ParseClockError::new(
+                ParseClockErrorKind::MissingSeparator,
+                s.len(),
+            ));
+        };
+        let start: Clock = start.parse()?;
+        let end_at = s.len() - end.len();
+        let (end, next_day) = match end.strip_suffix("+1d") {
+            Some(end) => (end, true),
+            None => (end, false),
+        };
+        let end_clock: Clock = end
+            .parse()
+            .map_err(|err: ParseClockError| err.offset(end_at))?;
+        if next_day {
+            if end_clock != start {
+                return Err(ParseClockError::new(
+                    ParseClockErrorKind::TrailingInput,
+                    end_at + end.len(),
+                ));
+            }
+            return Ok(ClockRange::from_start(start, ClockDuration::DAY));
+        }
+        if start == Clock::default() && end.starts_with("24") && end_clock == start {
+            return Ok(ClockRange::full_day());
+        }
+        Ok(ClockRange::new(start, end_clock))
+    }
+}
+
+#[test]
+fn test_range_across_midnight() {
+    let range = ClockRange::new(Clock::new(22, 0), Clock::new(2, 0));
+    assert!(range.wraps_midnight());
+    assert_eq!(range.duration(), ClockDuration::hours(4));
+    assert!(range.contains(Clock::new(23, 30)));
+    assert!(range.contains(Clock::new(0, 0)));
+    assert!(range.contains(Clock::new(1, 59)));
+    assert!(!range.contains(Clock::new(2, 0)));
+    assert!(!range.contains(Clock::new(12, 0)));
+}
+
+#[test]
+fn test_empty_and_full_day() {
+    let empty = ClockRange::new(Clock::new(9, 0), Clock::new(9, 0));
+    assert!(empty.is_empty());
+    assert!(!empty.contains(Clock::new(9, 0)));
+    let full = ClockRange::full_day();
+    assert!(full.contains(Clock::new(9, 0)));
+    assert_eq!(full.duration(), ClockDuration::DAY);
+    assert!(!full.wraps_midnight());
+}
+
+#[test]
+fn test_intersection() {
+    let night: ClockRange = "22:00-06:00".parse().unwrap();
+    let morning: ClockRange = "05:00-09:00".parse().unwrap();
+    assert_eq!(
+        night.intersection(&morning),
+        vec!["05:00-06:00".parse().unwrap()]
+    );
+    assert!(night.overlaps(&morning));
+    let noon: ClockRange = "11:00-13:00".parse().unwrap();
+    assert!(night.intersection(&noon).is_empty());
+    assert!(!night.overlaps(&noon));
+    let day: ClockRange = "04:00-23:00".parse().unwrap();
+    assert_eq!(
+        night.intersection(&day),
+        vec![
+            "04:00-06:00".parse().unwrap(),
+            "22:00-23:00".parse().unwrap()
+        ]
+    );
+}
+
+#[test]
+fn test_intersection_with_full_day() {
+    let night: ClockRange = "22:00-06:00".parse().unwrap();
+    assert_eq!(ClockRange::full_day().intersection(&night), vec![night]);
+    assert_eq!(night.intersection(&ClockRange::full_day()), vec![night]);
+    assert_eq!(
+        ClockRange::full_day().intersection(&ClockRange::full_day()),
+        vec![ClockRange::full_day()]
+    );
+}
+
+#[test]
+fn test_iteration() {
+    let range = ClockRange::new(Clock::new(23, 58), Clock::new(0, 1));
+    let minutes: Vec<String> = range.iter().map(|clock| clock.to_string()).collect();
+    assert_eq!(minutes, ["23:58", "23:59", "00:00"]);
+    let quarters = ClockRange::new(Clock::new(22, 0), Clock::new(23, 0))
+        .step_by(ClockDuration::minutes(15))
+        .count();
+    assert_eq!(quarters, 4);
+    assert_eq!(ClockRange::full_day().into_iter().count(), 1440);
+}
+
+#[test]
+fn test_display_and_parse() {
+    assert_eq!(
+        ClockRange::new(Clock::new(22, 0), Clock::new(2, 0)).to_string(),
+        "22:00-02:00"
+    );
+    assert_eq!(ClockRange::full_day().to_string(), "00:00-24:00");
+    assert_eq!(
+        "00:00-24:00".parse::<ClockRange>().unwrap(),
+        ClockRange::full_day()
+    );
+    let err = "22:00-2x:00".parse::<ClockRange>().unwrap_err();
+    assert_eq!(err.position(), 6);
+    let err = "22:00-02:00+1d".parse::<ClockRange>().unwrap_err();
+    assert_eq!(
+        (err.kind(), err.position()),
+        (ParseClockErrorKind::TrailingInput, 11)
+    );
+    assert_eq!(
+        "22:00".parse::<ClockRange>().unwrap_err().kind(),
+        ParseClockErrorKind::MissingSeparator
+    );
+}
+
+#[test]
+fn test_display_parse_round_trip() {
+    let ranges = [
+        ClockRange::full_day(),
+        ClockRange::from_start(Clock::new(6, 0), ClockDuration::DAY),
+        ClockRange::from_start(Clock::with_seconds(23, 59, 30), ClockDuration::DAY),
+        ClockRange::new(Clock::new(6, 0), Clock::new(6, 0)),
+        ClockRange::new(Clock::new(22, 0), Clock::new(2, 0)),
+    ];
+    for range in ranges {
+        assert_eq!(range.to_string().parse::<ClockRange>().unwrap(), range);
+    }
+    assert_eq!(ranges[1].to_string(), "06:00-06:00+1d");
+    assert_eq!(ranges[3].to_string(), "06:00-06:00");
+}
@@ -0,0 +1,261 @@
+//! A line based calculator session over [`expr`](crate::expr), as run by the
+//! `clock` binary.
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+};
+
+use crate::{
+    expr::{Expr, ExprError, Value},
+    Clock,
+};
+
+const HELP: &str = "\
+Enter an expression like `10:10 + 1:50 + 240m - 1h` or `diff(22:00, 06:30)`.
+Durations take the units h, m, s and ms, plain numbers count minutes.
+
+  name = expr     store a value, `_` holds the last result
+  :vars           list stored values
+  :format 12h     print clocks as `9:30 PM` (or `24h` for `21:30`)
+  :history        list earlier lines, `!!` or `!n` runs one again
+  :help           show this text
+  :quit           leave";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// Text to print, empty for lines that produce nothing.
+    Output(String),
+    Error(String),
+    Quit,
+}
+
+/// The state of a calculator session: variables, history and how clocks
+/// are printed.
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    variables: HashMap<String, Value>,
+    history: Vec<String>,
+    twelve_hour: bool,
+}
+
+fn is_name(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `err` with a caret line under the part of `line` it is about. The span
+/// is into `line[offset..]`.
+fn show_error(line: &str, offset: usize, err: &ExprError) -> String {
+    let span = err.span();
+    let start = line[..offset + span.start].chars().count();
+    let width = line[offset + span.start..offset + span.end]
+        .chars()
+        .count()
+        .max(1);
+    format!(
+        "{}\n{}{}\n{}",
+        line,
+        " ".repeat(start),
+        "^".repeat(width),
+        err
+    )
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn variables(&self) -> &HashMap<String, Value> {
+        &self.variables
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    pub fn format_value(&self, value: &Value) -> String {
+        match value {
+            Value::Clock(clock) if self.twelve_hour => clock.display_12h().to_string(),
+            value => value.to_string(),
+        }
+    }
+
+    pub fn eval_line(&mut self, line: &str) -> Reply {
+        let line = line.trim();
+        let line = match line {
+            "!!" => match self.history.last() {
+                Some(last) => last.clone(),
+                None => return Reply::Error("history is empty".to_string()),
+            },
+            _ if line.starts_with('!') => {
+                match line[1..]
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| self.history.get(n.checked_sub(1)?))
+                {
+                    Some(entry) => entry.clone(),
+                    None => return Reply::Error(format!("no history entry {}", &line[1..])),
+                }
+            }
+            _ => line.to_string(),
+        };
+        if line.is_empty() {
+            return Reply::Output(String::new());
+        }
+        if let Some(command) = line.strip_prefix(':') {
+            return self.command(command);
+        }
+        self.history.push(line.clone());
+        let (name, offset) = match line.split_once('=') {
+            Some((name, _)) if is_name(name.trim()) => (Some(name.trim()), name.len() + 1),
+            _ => (None, 0),
+        };
+        let value = match Expr::parse(&line[offset..]).and_then(|expr| expr.eval(&self.variables)) {
+            Ok(value) => value,
+            Err(err) => return Reply::Error(show_error(&line, offset, &err)),
+        };
+        self.variables.insert("_".to_string(), value);
+        match name {
+            Some(name) => {
+                self.variables.insert(name.to_string(), value);
+                Reply::Output(format!("{} = {}", name, self.format_value(&value)))
+            }
+            None => Reply::Output(self.format_value(&value)),
+        }
+    }
+
+    fn command(&mut self, command: &str) -> Reply {
+        let mut words = command.split_whitespace();
+        let output = match (words.next(), words.next()) {
+            (Some("help" | "h"), None) => HELP.to_string(),
+            (Some("quit" | "q"), None) => return Reply::Quit,
+            (Some("format"), Some(mode @ ("12h" | "24h"))) => {
+                self.twelve_hour = mode == "12h";
+                format!(
+                    "clocks print as {}",
+                    self.format_value(&Value::Clock(Clock::new(21, 30)))
+                )
+            }
+            (Some("vars"), None) => {
+                let mut names: Vec<&String> = self.variables.keys().collect();
+                names.sort();
+                names
+                    .into_iter()
+                    .map(|name| format!("{} = {}", name, self.format_value(&self.variables[name])))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            (Some("history"), None) => self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(idx, line)| format!("{:>4}  {}", idx + 1, line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            _ => return Reply::Error(format!("unknown command :{}, try :help", command)),
+        };
+        Reply::Output(output)
+    }
+}
+
+/// Reads lines from `input` until it ends or `:quit`, writing a prompt and
+/// the reply for each one to `output`.
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut session = Session::new();
+    write!(output, "> ")?;
+    output.flush()?;
+    for line in input.lines() {
+        match session.eval_line(&line?) {
+            Reply::Output(text) if text.is_empty() => {}
+            Reply::Output(text) | Reply::Error(text) => writeln!(output, "{}", text)?,
+            Reply::Quit => return Ok(()),
+        }
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+    writeln!(output)
+}
+
+#[test]
+fn test_variables_and_last_result() {
+    let mut session = Session::new();
+    assert_eq!(
+        session.eval_line("shift_start = 08:45"),
+        Reply::Output("shift_start = 08:45".to_string())
+    );
+    assert_eq!(
+        session.eval_line("shift_start + 8h30m"),
+        Reply::Output("17:15".to_string())
+    );
+    assert_eq!(
+        session.eval_line("diff(shift_start, _)"),
+        Reply::Output("8h30m".to_string())
+    );
+    assert_eq!(
+        session.eval_line(":vars"),
+        Reply::Output("_ = 8h30m\nshift_start = 08:45".to_string())
+    );
+}
+
+#[test]
+fn test_format_12h() {
+    let mut session = Session::new();
+    session.eval_line(":format 12h");
+    assert_eq!(
+        session.eval_line("21:30 + 15m"),
+        Reply::Output("9:45 PM".to_string())
+    );
+    assert_eq!(
+        session.eval_line("00:00:30"),
+        Reply::Output("12:00:30 AM".to_string())
+    );
+    session.eval_line(":format 24h");
+    assert_eq!(
+        session.eval_line("00:00"),
+        Reply::Output("00:00".to_string())
+    );
+}
+
+#[test]
+fn test_history() {
+    let mut session = Session::new();
+    session.eval_line("x = 10:00");
+    session.eval_line("x + 1h");
+    session.eval_line("x = 11:00");
+    assert_eq!(session.eval_line("!2"), Reply::Output("12:00".to_string()));
+    assert_eq!(session.eval_line("!!"), Reply::Output("12:00".to_string()));
+    assert_eq!(session.history().len(), 5);
+    assert_eq!(
+        session.eval_line(":history"),
+        Reply::Output(
+            "   1  x = 10:00\n   2  x + 1h\n   3  x = 11:00\n   4  x + 1h\n   5  x + 1h"
+                .to_string()
+        )
+    );
+    assert!(matches!(session.eval_line("!9"), Reply::Error(_)));
+}
+
+#[test]
+fn test_errors_point_at_span() {
+    let mut session = Session::new();
+    let Reply::Error(text) = session.eval_line("end = start + 1h") else {
+        panic!("expected an error");
+    };
+    let mut lines = text.lines();
+    assert_eq!(lines.next(), Some("end = start + 1h"));
+    assert_eq!(lines.next(), Some("      ^^^^^"));
+    assert!(matches!(session.eval_line(":nope"), Reply::Error(_)));
+}
+
+#[test]
+fn test_run() {
+    let input = b"a = 10:10\na + 1:50\n:quit\n1 + 1\n";
+    let mut output = Vec::new();
+    run(&input[..], &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "> a = 10:10\n> 12:00\n> "
+    );
+}
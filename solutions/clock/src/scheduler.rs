@@ -0,0 +1,380 @@
+//! A hierarchical timer wheel with a resolution of one second.
+//!
+//! Level 0 has one slot per second for the next 64 seconds, every level
+//! above covers 64 times the span of the one below. A timer sits in the
+//! lowest level whose slot span still tells it apart from the current tick
+//! and is cascaded down a level whenever the wheel below wraps around. Timers
+//! beyond the last level wait in an overflow list.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::{Clock, ClockDuration, DateTime, TimeSource, TimeZone, NANOS_PER_SECOND};
+
+const SLOT_BITS: u32 = 6;
+const SLOTS: usize = 1 << SLOT_BITS;
+const LEVELS: usize = 4;
+
+/// Cancels its timer when [`TimerHandle::cancel`] is called. Dropping the
+/// handle leaves the timer running.
+#[derive(Debug, Clone)]
+pub struct TimerHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TimerHandle {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+enum Repeat {
+    Once,
+    /// Every this many ticks.
+    Every(u64),
+    /// At this wall time every day in the scheduler's zone.
+    Daily(Clock),
+}
+
+struct Timer {
+    id: u64,
+    /// Ticks since the scheduler's origin.
+    when: u64,
+    repeat: Repeat,
+    cancelled: Arc<AtomicBool>,
+    callback: Box<dyn FnMut(DateTime) + Send>,
+}
+
+/// Runs callbacks at wall clock times, as seen by a [`TimeSource`].
+/// Nothing happens on its own: [`Scheduler::run_pending`] fires every timer
+/// that is due, so driving it with a [`MockTimeSource`](crate::MockTimeSource)
+/// makes tests deterministic.
+pub struct Scheduler<S> {
+    source: S,
+    zone: TimeZone,
+    /// The Unix second of tick 0.
+    origin: i64,
+    /// The last tick that was processed.
+    elapsed: u64,
+    wheel: Vec<Vec<Timer>>,
+    overflow: Vec<Timer>,
+    next_id: u64,
+}
+
+impl<S: TimeSource> Scheduler<S> {
+    /// A scheduler that reads daily times in UTC.
+    pub fn new(source: S) -> Self {
+        Self::with_zone(source, TimeZone::utc())
+    }
+
+    pub fn with_zone(source: S, zone: TimeZone) -> Self {
+        let origin = source.now().unix_seconds();
+        Self {
+            source,
+            zone,
+            origin,
+            elapsed: 0,
+            wheel: (0..LEVELS * SLOTS).map(|_| Vec::new()).collect(),
+            overflow: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Fires once, `delay` from now, rounded up to the next whole second.
+    pub fn schedule_in(
+        &mut self,
+        delay: ClockDuration,
+        callback: impl FnMut(DateTime) + Send + 'static,
+    ) -> TimerHandle {
+        let when = self.tick_after(delay);
+        self.schedule(when, Repeat::Once, Box::new(callback))
+    }
+
+    /// Fires once, at the next time the zone's wall clock shows `clock`.
+    pub fn schedule_at(
+        &mut self,
+        clock: Clock,
+        callback: impl FnMut(DateTime) + Send + 'static,
+    ) -> TimerHandle {
+        let when = self.next_daily(clock, self.current_tick());
+        self.schedule(when, Repeat::Once, Box::new(callback))
+    }
+
+    /// Fires every day when the zone's wall clock shows `clock`. On days
+    /// where `clock` falls into a DST gap it fires right after the gap.
+    pub fn schedule_daily(
+        &mut self,
+        clock: Clock,
+        callback: impl FnMut(DateTime) + Send + 'static,
+    ) -> TimerHandle {
+        let when = self.next_daily(clock, self.current_tick());
+        self.schedule(when, Repeat::Daily(clock), Box::new(callback))
+    }
+
+    /// Fires every `interval`, which is rounded up to whole seconds, starting
+    /// one interval from now.
+    pub fn schedule_every(
+        &mut self,
+        interval: ClockDuration,
+        callback: impl FnMut(DateTime) + Send + 'static,
+    ) -> TimerHandle {
+        assert!(interval > ClockDuration::ZERO, "interval must be positive");
+        let ticks = (interval.as_nanos() + NANOS_PER_SECOND - 1) / NANOS_PER_SECOND;
+        let when = self.tick_after(interval);
+        self.schedule(when, Repeat::Every(ticks as u64), Box::new(callback))
+    }
+
+    /// Fires every timer that is due at the time source's current time, in
+    /// order of their due time, and returns how many fired. A recurring
+    /// timer that was missed several times fires for each of them.
+    pub fn run_pending(&mut self) -> usize {
+        let target = self.current_tick();
+        let mut fired = 0;
+        while self.elapsed < target {
+            // Nothing can happen before the next time the lowest occupied
+            // level cascades.
+            let Some(level) = self.lowest_occupied_level() else {
+                self.elapsed = target;
+                break;
+            };
+            let span = 1u64 << (SLOT_BITS as usize * level);
+            let next = (self.elapsed / span + 1) * span;
+            if next > target {
+                self.elapsed = target;
+                break;
+            }
+            self.elapsed = next;
+            fired += self.process_tick();
+        }
+        fired
+    }
+
+    /// The tick of the time source's current time. A source that went
+    /// backwards does not undo ticks that were already processed.
+    fn current_tick(&self) -> u64 {
+        let tick = self.source.now().unix_seconds() - self.origin;
+        (tick.max(0) as u64).max(self.elapsed)
+    }
+
+    fn nanos_since_origin(&self) -> i64 {
+        let now = self.source.now();
+        (now.unix_seconds() - self.origin) * NANOS_PER_SECOND + now.clock().nanos() as i64
+    }
+
+    /// The tick `delay` from now, rounded up, and never a tick that was
+    /// already processed.
+    fn tick_after(&self, delay: ClockDuration) -> u64 {
+        let nanos = self.nanos_since_origin() + delay.as_nanos();
+        let tick = (nanos + NANOS_PER_SECOND - 1).div_euclid(NANOS_PER_SECOND);
+        tick.max(self.elapsed as i64 + 1) as u64
+    }
+
+    /// The first tick after `after` where the zone's wall clock shows
+    /// `clock`.
+    fn next_daily(&self, clock: Clock, after: u64) -> u64 {
+        let after = self.origin + after as i64;
+        let local = DateTime::from_unix(self.zone.utc_to_local(after), 0);
+        let mut date = local.date();
+        loop {
+            let utc = date
+                .and_clock(clock)
+                .convert_zone(&self.zone, &TimeZone::utc())
+                .earliest()
+                .unix_seconds();
+            if utc > after {
+                return (utc - self.origin) as u64;
+            }
+            date = date.succ();
+        }
+    }
+
+    fn schedule(
+        &mut self,
+        when: u64,
+        repeat: Repeat,
+        callback: Box<dyn FnMut(DateTime) + Send>,
+    ) -> TimerHandle {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.next_id += 1;
+        self.insert(Timer {
+            id: self.next_id,
+            when,
+            repeat,
+            cancelled: cancelled.clone(),
+            callback,
+        });
+        TimerHandle { cancelled }
+    }
+
+    fn insert(&mut self, timer: Timer) {
+        // The highest bit where `when` differs from now picks the level.
+        let masked = (self.elapsed ^ timer.when) | (SLOTS as u64 - 1);
+        let level = (63 - masked.leading_zeros()) / SLOT_BITS;
+        let level = level as usize;
+        if level >= LEVELS {
+            self.overflow.push(timer);
+        } else {
+            let slot = (timer.when >> (SLOT_BITS as usize * level)) as usize % SLOTS;
+            self.wheel[level * SLOTS + slot].push(timer);
+        }
+    }
+
+    fn lowest_occupied_level(&self) -> Option<usize> {
+        (0..LEVELS)
+            .find(|level| {
+                self.wheel[level * SLOTS..(level + 1) * SLOTS]
+                    .iter()
+                    .any(|slot| !slot.is_empty())
+            })
+            .or((!self.overflow.is_empty()).then_some(LEVELS))
+    }
+
+    fn process_tick(&mut self) -> usize {
+        let tick = self.elapsed;
+        if tick.is_multiple_of(1 << (SLOT_BITS as usize * LEVELS)) {
+            for timer in std::mem::take(&mut self.overflow) {
+                self.insert(timer);
+            }
+        }
+        for level in (1..LEVELS).rev() {
+            let shift = SLOT_BITS as usize * level;
+            if tick.is_multiple_of(1 << shift) {
+                let slot = (tick >> shift) as usize % SLOTS;
+                for timer in std::mem::take(&mut self.wheel[level * SLOTS + slot]) {
+                    self.insert(timer);
+                }
+            }
+        }
+        let mut due = std::mem::take(&mut self.wheel[tick as usize % SLOTS]);
+        due.sort_by_key(|timer| timer.id);
+        let mut fired = 0;
+        for mut timer in due {
+            if timer.cancelled.load(Ordering::Relaxed) {
+                continue;
+            }
+            (timer.callback)(DateTime::from_unix(self.origin + tick as i64, 0));
+            fired += 1;
+            let next = match timer.repeat {
+                Repeat::Once => continue,
+                Repeat::Every(ticks) => tick + ticks,
+                Repeat::Daily(clock) => self.next_daily(clock, tick),
+            };
+            timer.when = next;
+            self.insert(timer);
+        }
+        fired
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Scheduler<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("source", &self.source)
+            .field("zone", &self.zone)
+            .field("origin", &self.origin)
+            .field("elapsed", &self.elapsed)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+fn recorder() -> (
+    Arc<std::sync::Mutex<Vec<String>>>,
+    impl FnMut(DateTime) + Send + Clone + 'static,
+) {
+    let fired = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let log = fired.clone();
+    (fired, move |at: DateTime| {
+        log.lock().unwrap().push(at.to_string())
+    })
+}
+
+#[test]
+fn test_one_shot_in_45_minutes() {
+    let source = crate::MockTimeSource::new("2024-05-01T12:00".parse().unwrap());
+    let mut scheduler = Scheduler::new(source.clone());
+    let (fired, callback) = recorder();
+    scheduler.schedule_in(ClockDuration::minutes(45), callback);
+    source.advance(ClockDuration::minutes(44));
+    assert_eq!(scheduler.run_pending(), 0);
+    source.advance(ClockDuration::minutes(1));
+    assert_eq!(scheduler.run_pending(), 1);
+    source.advance(ClockDuration::hours(2));
+    assert_eq!(scheduler.run_pending(), 0);
+    assert_eq!(*fired.lock().unwrap(), ["2024-05-01T12:45"]);
+}
+
+#[test]
+fn test_daily_in_zone() {
+    let source = crate::MockTimeSource::new("2024-05-01T04:00".parse().unwrap());
+    let vienna = TimeZone::fixed(2 * 3600).unwrap();
+    let mut scheduler = Scheduler::with_zone(source.clone(), vienna);
+    let (fired, callback) = recorder();
+    scheduler.schedule_daily(Clock::new(7, 30), callback);
+    for _ in 0..48 {
+        source.advance(ClockDuration::hours(1));
+        scheduler.run_pending();
+    }
+    // 07:30 in UTC+2 is 05:30 UTC.
+    assert_eq!(
+        *fired.lock().unwrap(),
+        ["2024-05-01T05:30", "2024-05-02T05:30"]
+    );
+}
+
+#[test]
+fn test_cancel() {
+    let source = crate::MockTimeSource::new("2024-05-01T12:00".parse().unwrap());
+    let mut scheduler = Scheduler::new(source.clone());
+    let (fired, callback) = recorder();
+    let once = scheduler.schedule_in(ClockDuration::minutes(5), callback.clone());
+    let every = scheduler.schedule_every(ClockDuration::minutes(10), callback);
+    once.cancel();
+    assert!(once.is_cancelled());
+    source.advance(ClockDuration::minutes(25));
+    assert_eq!(scheduler.run_pending(), 2);
+    every.cancel();
+    source.advance(ClockDuration::hours(1));
+    assert_eq!(scheduler.run_pending(), 0);
+    assert_eq!(
+        *fired.lock().unwrap(),
+        ["2024-05-01T12:10", "2024-05-01T12:20"]
+    );
+}
+
+#[test]
+fn test_catch_up_in_order_across_levels() {
+    let source = crate::MockTimeSource::new("2024-01-01T00:00".parse().unwrap());
+    let mut scheduler = Scheduler::new(source.clone());
+    let (fired, callback) = recorder();
+    scheduler.schedule_in(ClockDuration::hours(24 * 300), callback.clone());
+    scheduler.schedule_at(Clock::new(0, 30), callback.clone());
+    scheduler.schedule_every(ClockDuration::hours(24 * 100), callback.clone());
+    scheduler.schedule_in(ClockDuration::seconds(1), callback);
+    source.advance(ClockDuration::hours(24 * 365));
+    assert_eq!(scheduler.run_pending(), 6);
+    assert_eq!(
+        *fired.lock().unwrap(),
+        [
+            "2024-01-01T00:00:01",
+            "2024-01-01T00:30",
+            "2024-04-10T00:00",
+            "2024-07-19T00:00",
+            "2024-10-27T00:00",
+            "2024-10-27T00:00"
+        ]
+    );
+}
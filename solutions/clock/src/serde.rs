@@ -0,0 +1,156 @@
+//! Serializes [`Clock`] as its `Display` string, e.g. `"12:30"`, and
+//! deserializes it with [`FromStr`](std::str::FromStr). Use [`minutes`] with
+//! `#[serde(with = "clock::serde::minutes")]` for a number instead.
+
+use std::fmt;
+
+use ::serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{error::FieldValue, Clock, ParseClockError};
+
+impl Serialize for Clock {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct ClockVisitor;
+
+impl de::Visitor<'_> for ClockVisitor {
+    type Value = Clock;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a time of day like \"12:30\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(|err: ParseClockError| E::custom(err))
+    }
+}
+
+impl<'de> Deserialize<'de> for Clock {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ClockVisitor)
+    }
+}
+
+impl Serialize for FieldValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FieldValue::String(value) => serializer.serialize_str(value),
+            FieldValue::Number(value) => serializer.serialize_u64(*value as u64),
+            FieldValue::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+impl Serialize for ParseClockError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = self.fields();
+        let mut state = serializer.serialize_struct("ParseClockError", fields.len())?;
+        for (name, value) in &fields {
+            state.serialize_field(name, value)?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes a [`Clock`] as whole minutes since midnight (`0..1440`).
+/// Clocks with seconds fail to serialize rather than losing precision.
+pub mod minutes {
+    use std::fmt;
+
+    use ::serde::{de, ser, Deserializer, Serializer};
+
+    use crate::Clock;
+
+    pub fn serialize<S: Serializer>(clock: &Clock, serializer: S) -> Result<S::Ok, S::Error> {
+        if clock.seconds() != 0 || clock.nanos() != 0 {
+            return Err(ser::Error::custom(format!(
+                "{} cannot be represented in whole minutes",
+                clock
+            )));
+        }
+        serializer.serialize_u16((clock.hours() * 60 + clock.minutes()) as u16)
+    }
+
+    struct MinutesVisitor;
+
+    impl de::Visitor<'_> for MinutesVisitor {
+        type Value = Clock;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "minutes since midnight between 0 and 1439")
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            match v {
+                0..=1439 => Ok(Clock::new(0, v as i32)),
+                _ => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+            }
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            match v {
+                0..=1439 => Ok(Clock::new(0, v as i32)),
+                _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+            }
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Clock, D::Error> {
+        deserializer.deserialize_u16(MinutesVisitor)
+    }
+}
+
+#[test]
+fn test_clock_as_string() {
+    let clock = Clock::with_seconds(12, 30, 15);
+    assert_eq!(serde_json::to_string(&clock).unwrap(), "\"12:30:15\"");
+    assert_eq!(
+        serde_json::from_str::<Clock>("\"12:30:15\"").unwrap(),
+        clock
+    );
+    let clocks: Vec<Clock> = serde_json::from_str("[\"08:00\", \"22:15\"]").unwrap();
+    assert_eq!(clocks, vec![Clock::new(8, 0), Clock::new(22, 15)]);
+}
+
+#[test]
+fn test_deserialize_error_is_parse_clock_error() {
+    let err = serde_json::from_str::<Clock>("\"12:xx\"").unwrap_err();
+    let expected = "12:xx".parse::<Clock>().unwrap_err();
+    assert!(err.to_string().starts_with(&expected.to_string()));
+    assert!(serde_json::from_str::<Clock>("750").is_err());
+}
+
+#[test]
+fn test_serialize_parse_clock_error() {
+    let err = "12:xx".parse::<Clock>().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        serde_json::json!({
+            "kind": "not_a_number",
+            "component": "minute",
+            "position": 3,
+            "message": err.to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_minutes_representation() {
+    let value = minutes::serialize(&Clock::new(12, 30), serde_json::value::Serializer).unwrap();
+    assert_eq!(value, serde_json::json!(750));
+    assert_eq!(
+        minutes::deserialize(serde_json::json!(750)).unwrap(),
+        Clock::new(12, 30)
+    );
+    assert!(minutes::deserialize(serde_json::json!(1440)).is_err());
+    assert!(minutes::deserialize(serde_json::json!(-1)).is_err());
+    assert!(minutes::deserialize(serde_json::json!("12:30")).is_err());
+    assert!(minutes::serialize(
+        &Clock::with_seconds(12, 30, 1),
+        serde_json::value::Serializer
+    )
+    .is_err());
+}
@@ -0,0 +1,245 @@
+//! An analog clock face for a [`Clock`] as a standalone SVG document, with
+//! hands at the angles of [`Clock::hour_hand_angle`] and friends.
+
+use std::fmt;
+
+use crate::Clock;
+
+/// Which marks [`AnalogSvg`] draws around the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ticks {
+    None,
+    Hours,
+    /// Hour marks plus a thinner mark for every minute.
+    #[default]
+    Minutes,
+}
+
+/// The hour labels inside the ticks of an [`AnalogSvg`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Numerals {
+    None,
+    #[default]
+    Arabic,
+    /// `I` to `XII`, with the watchmaker's `IIII` for four.
+    Roman,
+}
+
+/// How [`AnalogSvg`] draws the hour and minute hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HandStyle {
+    /// Straight bars with rounded ends.
+    #[default]
+    Line,
+    /// Filled shapes narrowing towards the tip.
+    Tapered,
+}
+
+const ROMAN: [&str; 12] = [
+    "XII", "I", "II", "III", "IIII", "V", "VI", "VII", "VIII", "IX", "X", "XI",
+];
+
+/// A [`Clock`] as an analog clock face in SVG, rendered when displayed.
+/// Created by [`Clock::analog_svg`]. The drawing uses a 200 unit viewBox
+/// centered on the dial, so [`AnalogSvg::size`] only scales it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnalogSvg {
+    clock: Clock,
+    size: u32,
+    ticks: Ticks,
+    numerals: Numerals,
+    hands: HandStyle,
+    second_hand: bool,
+}
+
+impl AnalogSvg {
+    /// Width and height in pixels, 200 by default.
+    pub fn size(self, size: u32) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Marks around the dial, [`Ticks::Minutes`] by default.
+    pub fn ticks(self, ticks: Ticks) -> Self {
+        Self { ticks, ..self }
+    }
+
+    /// Hour labels, [`Numerals::Arabic`] by default.
+    pub fn numerals(self, numerals: Numerals) -> Self {
+        Self { numerals, ..self }
+    }
+
+    /// Hour and minute hand shape, [`HandStyle::Line`] by default.
+    pub fn hands(self, hands: HandStyle) -> Self {
+        Self { hands, ..self }
+    }
+
+    /// Adds a thin red second hand, off by default.
+    pub fn second_hand(self, second_hand: bool) -> Self {
+        Self {
+            second_hand,
+            ..self
+        }
+    }
+
+    fn write_hand(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        angle: f64,
+        length: f64,
+        width: f64,
+    ) -> fmt::Result {
+        match self.hands {
+            HandStyle::Line => writeln!(
+                f,
+                r#"<line y1="10" y2="{}" stroke="black" stroke-width="{}" stroke-linecap="round" transform="rotate({})"/>"#,
+                -length,
+                width,
+                round(angle)
+            ),
+            HandStyle::Tapered => writeln!(
+                f,
+                r#"<polygon points="{},10 {},10 {},{} {},{}" fill="black" transform="rotate({})"/>"#,
+                -width,
+                width,
+                width / 4.0,
+                -length,
+                -width / 4.0,
+                -length,
+                round(angle)
+            ),
+        }
+    }
+}
+
+/// Two decimals are plenty at any sensible size, and keep `-0` out.
+fn round(value: f64) -> f64 {
+    let value = (value * 100.0).round() / 100.0;
+    if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+impl fmt::Display for AnalogSvg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="-100 -100 200 200">"#,
+            self.size
+        )?;
+        writeln!(
+            f,
+            r#"<circle r="97" fill="white" stroke="black" stroke-width="3"/>"#
+        )?;
+        if self.ticks != Ticks::None {
+            writeln!(f, r#"<g stroke="black">"#)?;
+            for minute in 0..60 {
+                let (inner, width) = match (minute % 5, self.ticks) {
+                    (0, _) => (-82, 3),
+                    (_, Ticks::Minutes) => (-88, 1),
+                    _ => continue,
+                };
+                writeln!(
+                    f,
+                    r#"<line y1="-92" y2="{}" stroke-width="{}" transform="rotate({})"/>"#,
+                    inner,
+                    width,
+                    minute * 6
+                )?;
+            }
+            writeln!(f, "</g>")?;
+        }
+        if self.numerals != Numerals::None {
+            writeln!(
+                f,
+                r#"<g font-family="sans-serif" font-size="14" text-anchor="middle" dominant-baseline="central">"#
+            )?;
+            for (hour, roman) in ROMAN.into_iter().enumerate() {
+                let angle = (hour as f64 * 30.0).to_radians();
+                let (x, y) = (round(70.0 * angle.sin()), round(-70.0 * angle.cos()));
+                write!(f, r#"<text x="{}" y="{}">"#, x, y)?;
+                match (self.numerals, hour) {
+                    (Numerals::Roman, _) => f.write_str(roman)?,
+                    (_, 0) => f.write_str("12")?,
+                    (_, hour) => write!(f, "{}", hour)?,
+                }
+                writeln!(f, "</text>")?;
+            }
+            writeln!(f, "</g>")?;
+        }
+        self.write_hand(f, self.clock.hour_hand_angle(), 50.0, 6.0)?;
+        self.write_hand(f, self.clock.minute_hand_angle(), 75.0, 4.0)?;
+        if self.second_hand {
+            writeln!(
+                f,
+                r##"<line y1="15" y2="-82" stroke="#c00" stroke-width="1.5" transform="rotate({})"/>"##,
+                round(self.clock.second_hand_angle())
+            )?;
+        }
+        writeln!(f, r#"<circle r="4" fill="black"/>"#)?;
+        write!(f, "</svg>")
+    }
+}
+
+impl Clock {
+    /// An analog clock face with hour and minute hands, minute ticks and
+    /// Arabic numerals, see [`AnalogSvg`] for the options.
+    pub fn analog_svg(&self) -> AnalogSvg {
+        AnalogSvg {
+            clock: *self,
+            size: 200,
+            ticks: Ticks::default(),
+            numerals: Numerals::default(),
+            hands: HandStyle::default(),
+            second_hand: false,
+        }
+    }
+}
+
+#[test]
+fn test_svg_default_face() {
+    let svg = Clock::new(3, 0).analog_svg().to_string();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="200""#));
+    assert!(svg.ends_with("</svg>"));
+    assert_eq!(svg.matches("<line y1=\"-92\"").count(), 60);
+    assert_eq!(svg.matches("<text").count(), 12);
+    assert!(svg.contains(r#"<text x="70" y="0">3</text>"#));
+    assert!(svg.contains(r#"<text x="0" y="-70">12</text>"#));
+    assert!(svg.contains(
+        r#"y2="-50" stroke="black" stroke-width="6" stroke-linecap="round" transform="rotate(90)""#
+    ));
+    assert!(svg.contains(
+        r#"y2="-75" stroke="black" stroke-width="4" stroke-linecap="round" transform="rotate(0)""#
+    ));
+    assert!(!svg.contains("#c00"));
+}
+
+#[test]
+fn test_svg_options() {
+    let svg = Clock::with_seconds(21, 30, 15)
+        .analog_svg()
+        .size(64)
+        .ticks(Ticks::Hours)
+        .numerals(Numerals::Roman)
+        .hands(HandStyle::Tapered)
+        .second_hand(true)
+        .to_string();
+    assert!(svg.contains(r#"width="64" height="64""#));
+    assert_eq!(svg.matches("<line y1=\"-92\"").count(), 12);
+    assert!(svg.contains(">XII</text>"));
+    assert!(svg.contains(">IIII</text>"));
+    assert!(svg.contains(
+        r#"<polygon points="-6,10 6,10 1.5,-50 -1.5,-50" fill="black" transform="rotate(285.13)"/>"#
+    ));
+    assert!(svg.contains(r#"transform="rotate(181.5)""#));
+    assert!(svg.contains(r##"stroke="#c00" stroke-width="1.5" transform="rotate(90)""##));
+
+    let bare = Clock::new(6, 0)
+        .analog_svg()
+        .ticks(Ticks::None)
+        .numerals(Numerals::None)
+        .to_string();
+    assert!(!bare.contains("<g"));
+    assert_eq!(bare.lines().count(), 6);
+}
@@ -0,0 +1,133 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Clock, ClockDuration, DateTime, TimeZone, NANOS_PER_SECOND};
+
+/// Where the current time comes from. Code that asks a `TimeSource` instead
+/// of the system can be tested with a [`MockTimeSource`].
+pub trait TimeSource {
+    /// The current date and time in UTC.
+    fn now(&self) -> DateTime;
+
+    /// The current wall time in `zone`.
+    fn now_in(&self, zone: &TimeZone) -> DateTime {
+        let utc = self.now();
+        DateTime::from_unix(zone.utc_to_local(utc.unix_seconds()), utc.clock().nanos())
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for &T {
+    fn now(&self) -> DateTime {
+        (**self).now()
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for Arc<T> {
+    fn now(&self) -> DateTime {
+        (**self).now()
+    }
+}
+
+/// The time of the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> DateTime {
+        let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_nanos() as i64,
+            Err(err) => -(err.duration().as_nanos() as i64),
+        };
+        DateTime::from_unix(
+            nanos.div_euclid(NANOS_PER_SECOND),
+            nanos.rem_euclid(NANOS_PER_SECOND) as i32,
+        )
+    }
+}
+
+/// A time source that only moves when told to. Clones share the same time,
+/// so a test can keep one and hand another to the code under test, also on
+/// other threads.
+#[derive(Debug, Clone)]
+pub struct MockTimeSource {
+    now: Arc<Mutex<DateTime>>,
+}
+
+impl MockTimeSource {
+    pub fn new(now: DateTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    pub fn set(&self, now: DateTime) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, by: ClockDuration) {
+        let mut now = self.now.lock().unwrap();
+        *now = *now + by;
+    }
+}
+
+impl TimeSource for MockTimeSource {
+    fn now(&self) -> DateTime {
+        *self.now.lock().unwrap()
+    }
+}
+
+impl Clock {
+    /// The current wall time in [`TimeZone::local`].
+    pub fn now() -> Clock {
+        Clock::now_from(&SystemTimeSource, &TimeZone::local())
+    }
+
+    pub fn now_utc() -> Clock {
+        Clock::now_from(&SystemTimeSource, &TimeZone::utc())
+    }
+
+    pub fn now_from(source: &impl TimeSource, zone: &TimeZone) -> Clock {
+        source.now_in(zone).clock()
+    }
+}
+
+#[test]
+fn test_mock_set_and_advance() {
+    let source = MockTimeSource::new("2024-03-31T23:30".parse().unwrap());
+    assert_eq!(
+        Clock::now_from(&source, &TimeZone::utc()),
+        Clock::new(23, 30)
+    );
+    source.advance(ClockDuration::minutes(45));
+    assert_eq!(source.now().to_string(), "2024-04-01T00:15");
+    source.set("2024-01-01T08:00".parse().unwrap());
+    let vienna = TimeZone::fixed(3600).unwrap();
+    assert_eq!(Clock::now_from(&source, &vienna), Clock::new(9, 0));
+    assert_eq!(source.now_in(&vienna).to_string(), "2024-01-01T09:00");
+}
+
+#[test]
+fn test_mock_shared_across_threads() {
+    let source = MockTimeSource::new("2024-01-01T00:00".parse().unwrap());
+    let shared: Arc<dyn TimeSource + Send + Sync> = Arc::new(source.clone());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let source = source.clone();
+            std::thread::spawn(move || source.advance(ClockDuration::hours(1)))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(shared.now().clock(), Clock::new(4, 0));
+}
+
+#[test]
+fn test_system_time_source() {
+    let now = SystemTimeSource.now();
+    assert!(now.date().year() >= 2024);
+    let utc = Clock::now_utc();
+    assert!(utc - now.clock() < ClockDuration::minutes(1));
+}
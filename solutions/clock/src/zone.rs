@@ -0,0 +1,896 @@
+use std::{fmt, io, path::PathBuf, str::FromStr, sync::Arc};
+
+use crate::{
+    civil::{
+        civil_from_days, days_from_civil, days_in_month, is_leap_year, weekday_from_days,
+        SECONDS_PER_DAY,
+    },
+    Clock, Date, DateTime,
+};
+
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+const LOCALTIME_PATH: &str = "/etc/localtime";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeType {
+    utc_offset: i32,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+impl LocalTimeType {
+    /// Seconds east of UTC.
+    pub fn utc_offset(&self) -> i32 {
+        self.utc_offset
+    }
+
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
+
+/// The outcome of mapping a local wall time to an instant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalResult<T> {
+    Single(T),
+    /// The wall time occurs twice (clocks were turned back). The earlier
+    /// instant comes first.
+    Ambiguous(T, T),
+    /// The wall time was skipped (clocks were turned forward). The value is
+    /// what the time maps to with the offset in effect before the gap, which
+    /// pushes it past the gap.
+    Gap(T),
+}
+
+impl<T> LocalResult<T> {
+    pub fn single(self) -> Option<T> {
+        match self {
+            LocalResult::Single(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn earliest(self) -> T {
+        match self {
+            LocalResult::Single(value) | LocalResult::Gap(value) => value,
+            LocalResult::Ambiguous(earlier, _) => earlier,
+        }
+    }
+
+    pub fn latest(self) -> T {
+        match self {
+            LocalResult::Single(value) | LocalResult::Gap(value) => value,
+            LocalResult::Ambiguous(_, later) => later,
+        }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> LocalResult<U> {
+        match self {
+            LocalResult::Single(value) => LocalResult::Single(f(value)),
+            LocalResult::Ambiguous(earlier, later) => LocalResult::Ambiguous(f(earlier), f(later)),
+            LocalResult::Gap(value) => LocalResult::Gap(f(value)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum TimeZoneError {
+    Io(io::Error),
+    InvalidName(String),
+    InvalidOffset(String),
+    InvalidTzif(&'static str),
+    InvalidPosixTz(String),
+}
+
+impl fmt::Display for TimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZoneError::Io(err) => write!(f, "Error reading time zone: {}", err),
+            TimeZoneError::InvalidName(name) => write!(f, "Invalid time zone name '{}'", name),
+            TimeZoneError::InvalidOffset(offset) => write!(f, "Invalid UTC offset '{}'", offset),
+            TimeZoneError::InvalidTzif(reason) => write!(f, "Invalid TZif data: {}", reason),
+            TimeZoneError::InvalidPosixTz(tz) => write!(f, "Invalid POSIX TZ string '{}'", tz),
+        }
+    }
+}
+
+impl std::error::Error for TimeZoneError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TimeZoneError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TimeZoneError {
+    fn from(err: io::Error) -> Self {
+        TimeZoneError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: day 1..=365, February 29th is never counted.
+    Julian(u16),
+    /// `n`: day 0..=365, February 29th is counted.
+    ZeroBased(u16),
+    /// `Mm.w.d`: day `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay(u32, u32, u32),
+}
+
+impl RuleDate {
+    fn days_since_epoch(&self, year: i32) -> i64 {
+        let jan_first = days_from_civil(year, 1, 1);
+        match *self {
+            RuleDate::Julian(day) => {
+                let leap_day = (is_leap_year(year) && day >= 60) as i64;
+                jan_first + day as i64 - 1 + leap_day
+            }
+            RuleDate::ZeroBased(day) => jan_first + day as i64,
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first = days_from_civil(year, month, 1);
+                let offset = (weekday as i64 - weekday_from_days(first) as i64).rem_euclid(7);
+                let mut day = offset + (week as i64 - 1) * 7;
+                while day >= days_in_month(year, month) as i64 {
+                    day -= 7;
+                }
+                first + day
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DstRule {
+    dst: LocalTimeType,
+    start: RuleDate,
+    start_time: i32,
+    end: RuleDate,
+    end_time: i32,
+}
+
+/// The rule from a TZif footer, e.g. `CET-1CEST,M3.5.0,M10.5.0/3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixTz {
+    std: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+impl PosixTz {
+    fn local_time_type(&self, utc: i64) -> &LocalTimeType {
+        let Some(rule) = &self.dst else {
+            return &self.std;
+        };
+        let (year, _, _) =
+            civil_from_days((utc + self.std.utc_offset as i64).div_euclid(SECONDS_PER_DAY));
+        let start = rule.start.days_since_epoch(year) * SECONDS_PER_DAY + rule.start_time as i64
+            - self.std.utc_offset as i64;
+        let end = rule.end.days_since_epoch(year) * SECONDS_PER_DAY + rule.end_time as i64
+            - rule.dst.utc_offset as i64;
+        let in_dst = if start < end {
+            start <= utc && utc < end
+        } else {
+            !(end <= utc && utc < start)
+        };
+        if in_dst {
+            &rule.dst
+        } else {
+            &self.std
+        }
+    }
+}
+
+struct PosixTzParser<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> PosixTzParser<'a> {
+    fn parse(input: &'a str) -> Result<PosixTz, TimeZoneError> {
+        let mut parser = PosixTzParser { input, rest: input };
+        let std_name = parser.name()?;
+        let std_offset = -parser.time()?;
+        let std = LocalTimeType {
+            utc_offset: std_offset,
+            is_dst: false,
+            abbreviation: std_name.to_string(),
+        };
+        if parser.rest.is_empty() {
+            return Ok(PosixTz { std, dst: None });
+        }
+        let dst_name = parser.name()?;
+        let dst_offset = if parser.rest.starts_with(',') {
+            std_offset + 3600
+        } else {
+            -parser.time()?
+        };
+        let dst = LocalTimeType {
+            utc_offset: dst_offset,
+            is_dst: true,
+            abbreviation: dst_name.to_string(),
+        };
+        parser.expect(',')?;
+        let (start, start_time) = parser.rule_date()?;
+        parser.expect(',')?;
+        let (end, end_time) = parser.rule_date()?;
+        if !parser.rest.is_empty() {
+            return Err(parser.error());
+        }
+        Ok(PosixTz {
+            std,
+            dst: Some(DstRule {
+                dst,
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    fn error(&self) -> TimeZoneError {
+        TimeZoneError::InvalidPosixTz(self.input.to_string())
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), TimeZoneError> {
+        self.rest = self.rest.strip_prefix(c).ok_or_else(|| self.error())?;
+        Ok(())
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let end = self.rest.find(|c| !pred(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    fn name(&mut self) -> Result<&'a str, TimeZoneError> {
+        let name = if self.rest.starts_with('<') {
+            self.expect('<')?;
+            let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-');
+            self.expect('>')?;
+            name
+        } else {
+            self.take_while(|c| c.is_ascii_alphabetic())
+        };
+        if name.len() < 3 {
+            return Err(self.error());
+        }
+        Ok(name)
+    }
+
+    fn number(&mut self) -> Result<i32, TimeZoneError> {
+        self.take_while(|c| c.is_ascii_digit())
+            .parse()
+            .map_err(|_| self.error())
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds, hours may go up to 167.
+    fn time(&mut self) -> Result<i32, TimeZoneError> {
+        let sign = if self.rest.starts_with('-') { -1 } else { 1 };
+        if self.rest.starts_with(['+', '-']) {
+            self.rest = &self.rest[1..];
+        }
+        let mut seconds = self.bounded_number(167)? * 3600;
+        for factor in [60, 1] {
+            if self.rest.starts_with(':') {
+                self.expect(':')?;
+                seconds += self.bounded_number(59)? * factor;
+            }
+        }
+        Ok(sign * seconds)
+    }
+
+    fn bounded_number(&mut self, max: i32) -> Result<i32, TimeZoneError> {
+        match self.number()? {
+            number @ 0.. if number <= max => Ok(number),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn rule_date(&mut self) -> Result<(RuleDate, i32), TimeZoneError> {
+        let date = if self.rest.starts_with('J') {
+            self.expect('J')?;
+            match self.number()? {
+                day @ 1..=365 => RuleDate::Julian(day as u16),
+                _ => return Err(self.error()),
+            }
+        } else if self.rest.starts_with('M') {
+            self.expect('M')?;
+            let month = self.number()?;
+            self.expect('.')?;
+            let week = self.number()?;
+            self.expect('.')?;
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday)
+            {
+                return Err(self.error());
+            }
+            RuleDate::MonthWeekDay(month as u32, week as u32, weekday as u32)
+        } else {
+            match self.number()? {
+                day @ 0..=365 => RuleDate::ZeroBased(day as u16),
+                _ => return Err(self.error()),
+            }
+        };
+        let time = if self.rest.starts_with('/') {
+            self.expect('/')?;
+            self.time()?
+        } else {
+            2 * 3600
+        };
+        Ok((date, time))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Tzif {
+    transitions: Vec<i64>,
+    transition_types: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    footer: Option<PosixTz>,
+}
+
+impl Tzif {
+    fn local_time_type(&self, utc: i64) -> &LocalTimeType {
+        let idx = self.transitions.partition_point(|&t| t <= utc);
+        match &self.footer {
+            Some(footer) if idx == self.transitions.len() => footer.local_time_type(utc),
+            _ if idx == 0 => &self.types[0],
+            _ => &self.types[self.transition_types[idx - 1]],
+        }
+    }
+}
+
+struct TzifReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TzifReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], TimeZoneError> {
+        if self.data.len() < len {
+            return Err(TimeZoneError::InvalidTzif("unexpected end of data"));
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, TimeZoneError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i32(&mut self) -> Result<i32, TimeZoneError> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, TimeZoneError> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn header(&mut self) -> Result<(u8, [usize; 6]), TimeZoneError> {
+        if self.take(4)? != b"TZif" {
+            return Err(TimeZoneError::InvalidTzif("missing TZif magic"));
+        }
+        let version = self.u8()?;
+        self.take(15)?;
+        let mut counts = [0; 6];
+        for count in counts.iter_mut() {
+            *count = self.i32()? as u32 as usize;
+        }
+        Ok((version, counts))
+    }
+
+    fn data_block(&mut self, counts: [usize; 6], time_size: usize) -> Result<Tzif, TimeZoneError> {
+        let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+        if typecnt == 0 {
+            return Err(TimeZoneError::InvalidTzif("no local time types"));
+        }
+        let transitions = (0..timecnt)
+            .map(|_| match time_size {
+                4 => self.i32().map(i64::from),
+                _ => self.i64(),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let transition_types = self
+            .take(timecnt)?
+            .iter()
+            .map(|&idx| idx as usize)
+            .collect::<Vec<_>>();
+        if transition_types.iter().any(|&idx| idx >= typecnt) {
+            return Err(TimeZoneError::InvalidTzif("transition type out of range"));
+        }
+        let raw_types = (0..typecnt)
+            .map(|_| Ok((self.i32()?, self.u8()? != 0, self.u8()? as usize)))
+            .collect::<Result<Vec<_>, TimeZoneError>>()?;
+        let chars = self.take(charcnt)?;
+        let types = raw_types
+            .into_iter()
+            .map(|(utc_offset, is_dst, abbr_idx)| {
+                let abbr = chars
+                    .get(abbr_idx..)
+                    .and_then(|abbr| abbr.split(|&b| b == 0).next())
+                    .ok_or(TimeZoneError::InvalidTzif("abbreviation out of range"))?;
+                Ok(LocalTimeType {
+                    utc_offset,
+                    is_dst,
+                    abbreviation: String::from_utf8_lossy(abbr).into_owned(),
+                })
+            })
+            .collect::<Result<Vec<_>, TimeZoneError>>()?;
+        // Leap second records and the standard/wall and UT/local indicators
+        // are not needed to map between UTC and local time.
+        self.take(leapcnt * (time_size + 4) + isstdcnt + isutcnt)?;
+        Ok(Tzif {
+            transitions,
+            transition_types,
+            types,
+            footer: None,
+        })
+    }
+
+    fn footer(&mut self) -> Result<Option<PosixTz>, TimeZoneError> {
+        let footer = self
+            .data
+            .strip_prefix(b"\n")
+            .and_then(|rest| rest.split(|&b| b == b'\n').next())
+            .ok_or(TimeZoneError::InvalidTzif("missing footer"))?;
+        let footer = std::str::from_utf8(footer)
+            .map_err(|_| TimeZoneError::InvalidTzif("footer is not UTF-8"))?;
+        if footer.is_empty() {
+            Ok(None)
+        } else {
+            PosixTzParser::parse(footer).map(Some)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Rules {
+    Fixed(LocalTimeType),
+    Tzif(Tzif),
+}
+
+/// A time zone, either a fixed UTC offset or a set of rules read from a
+/// TZif file. Cloning is cheap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZone {
+    name: Arc<str>,
+    rules: Arc<Rules>,
+}
+
+impl TimeZone {
+    pub fn utc() -> Self {
+        TimeZone {
+            name: "UTC".into(),
+            rules: Arc::new(Rules::Fixed(LocalTimeType {
+                utc_offset: 0,
+                is_dst: false,
+                abbreviation: "UTC".to_string(),
+            })),
+        }
+    }
+
+    pub fn fixed(utc_offset: i32) -> Result<Self, TimeZoneError> {
+        let abs = utc_offset.unsigned_abs();
+        if abs >= 24 * 3600 {
+            return Err(TimeZoneError::InvalidOffset(utc_offset.to_string()));
+        }
+        let sign = if utc_offset < 0 { '-' } else { '+' };
+        let mut name = format!("{}{:0>2}:{:0>2}", sign, abs / 3600, abs % 3600 / 60);
+        if !abs.is_multiple_of(60) {
+            name.push_str(&format!(":{:0>2}", abs % 60));
+        }
+        Ok(TimeZone {
+            rules: Arc::new(Rules::Fixed(LocalTimeType {
+                utc_offset,
+                is_dst: false,
+                abbreviation: name.clone(),
+            })),
+            name: name.into(),
+        })
+    }
+
+    /// Parses `+HH:MM`, `+HHMM` or `+HH` (or with `-`).
+    pub fn parse_offset(offset: &str) -> Result<Self, TimeZoneError> {
+        let error = || TimeZoneError::InvalidOffset(offset.to_string());
+        let sign = match offset.as_bytes().first() {
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            _ => return Err(error()),
+        };
+        let rest = &offset[1..];
+        if !rest.is_ascii() {
+            return Err(error());
+        }
+        let (hours, minutes) = match rest.len() {
+            2 => (rest, "00"),
+            4 => rest.split_at(2),
+            5 if &rest[2..3] == ":" => (&rest[..2], &rest[3..]),
+            _ => return Err(error()),
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return Err(error());
+        }
+        let hours: i32 = hours.parse().map_err(|_| error())?;
+        let minutes: i32 = minutes.parse().map_err(|_| error())?;
+        if hours > 23 || minutes > 59 {
+            return Err(error());
+        }
+        Self::fixed(sign * (hours * 3600 + minutes * 60))
+    }
+
+    /// Reads `name` from `$TZDIR`, falling back to `/usr/share/zoneinfo`.
+    pub fn load(name: &str) -> Result<Self, TimeZoneError> {
+        let is_valid = !name.is_empty()
+            && !name.starts_with('/')
+            && name
+                .split('/')
+                .all(|part| !part.is_empty() && part != "." && part != "..");
+        if !is_valid {
+            return Err(TimeZoneError::InvalidName(name.to_string()));
+        }
+        let dir = std::env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ZONEINFO_DIR));
+        let data = std::fs::read(dir.join(name))?;
+        Self::from_tzif(name, &data)
+    }
+
+    /// The zone named by `$TZ`, else the one in `/etc/localtime`, else UTC.
+    pub fn local() -> Self {
+        if let Ok(Ok(zone)) = std::env::var("TZ").map(|tz| tz.trim_start_matches(':').parse()) {
+            return zone;
+        }
+        std::fs::read(LOCALTIME_PATH)
+            .ok()
+            .and_then(|data| Self::from_tzif("localtime", &data).ok())
+            .unwrap_or_else(Self::utc)
+    }
+
+    pub fn from_tzif(name: &str, data: &[u8]) -> Result<Self, TimeZoneError> {
+        let mut reader = TzifReader { data };
+        let (version, counts) = reader.header()?;
+        let tzif = if version == 0 {
+            reader.data_block(counts, 4)?
+        } else {
+            let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+            reader.take(timecnt * 5 + typecnt * 6 + charcnt + leapcnt * 8 + isstdcnt + isutcnt)?;
+            let (_, counts) = reader.header()?;
+            let mut tzif = reader.data_block(counts, 8)?;
+            tzif.footer = reader.footer()?;
+            tzif
+        };
+        Ok(TimeZone {
+            name: name.into(),
+            rules: Arc::new(Rules::Tzif(tzif)),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The local time type in effect at `utc`, in seconds since the Unix epoch.
+    pub fn local_time_type(&self, utc: i64) -> &LocalTimeType {
+        match &*self.rules {
+            Rules::Fixed(local_time_type) => local_time_type,
+            Rules::Tzif(tzif) => tzif.local_time_type(utc),
+        }
+    }
+
+    pub fn utc_to_local(&self, utc: i64) -> i64 {
+        utc + self.local_time_type(utc).utc_offset as i64
+    }
+
+    /// Maps a local wall time, in seconds since the local epoch, to UTC.
+    pub fn local_to_utc(&self, local: i64) -> LocalResult<i64> {
+        let before = self.local_time_type(local - SECONDS_PER_DAY).utc_offset as i64;
+        let after = self.local_time_type(local + SECONDS_PER_DAY).utc_offset as i64;
+        let mut candidates = [before, after]
+            .into_iter()
+            .map(|offset| local - offset)
+            .filter(|&utc| self.utc_to_local(utc) == local)
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        candidates.dedup();
+        match candidates[..] {
+            [utc] => LocalResult::Single(utc),
+            [earlier, later] => LocalResult::Ambiguous(earlier, later),
+            _ => LocalResult::Gap(local - before),
+        }
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = TimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Z" | "UTC" => Ok(TimeZone::utc()),
+            _ if s.starts_with(['+', '-']) => TimeZone::parse_offset(s),
+            _ => TimeZone::load(s),
+        }
+    }
+}
+
+/// A [`Clock`] reading in a particular [`TimeZone`]. Since offsets change
+/// over the year, conversions need the calendar date of the reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZonedClock {
+    clock: Clock,
+    zone: TimeZone,
+}
+
+impl ZonedClock {
+    pub fn new(clock: Clock, zone: TimeZone) -> Self {
+        Self { clock, zone }
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    pub fn zone(&self) -> &TimeZone {
+        &self.zone
+    }
+
+    /// The instant(s) this reading denotes on the given date, as seconds since
+    /// the Unix epoch plus the sub-second nanos.
+    pub fn to_utc_on(&self, year: i32, month: u32, day: u32) -> LocalResult<(i64, i32)> {
+        self.to_utc_on_date(date_from_ymd(year, month, day))
+            .map(|utc| (utc.unix_seconds(), utc.clock().nanos()))
+    }
+
+    pub fn offset_on(&self, year: i32, month: u32, day: u32) -> LocalResult<&LocalTimeType> {
+        self.offset_on_date(date_from_ymd(year, month, day))
+    }
+
+    /// Converts the reading on the given (local) date into `target`.
+    pub fn to_zone_on(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        target: &TimeZone,
+    ) -> LocalResult<ZonedClock> {
+        self.to_zone_on_date(date_from_ymd(year, month, day), target)
+    }
+
+    /// The UTC date and time(s) this reading denotes on the given local date.
+    pub fn to_utc_on_date(&self, date: Date) -> LocalResult<DateTime> {
+        date.and_clock(self.clock)
+            .convert_zone(&self.zone, &TimeZone::utc())
+    }
+
+    pub fn offset_on_date(&self, date: Date) -> LocalResult<&LocalTimeType> {
+        self.zone
+            .local_to_utc(date.and_clock(self.clock).unix_seconds())
+            .map(|utc| self.zone.local_time_type(utc))
+    }
+
+    /// Converts the reading on the given local date into `target`. Use
+    /// [`DateTime::convert_zone`] to also learn the date in `target`.
+    pub fn to_zone_on_date(&self, date: Date, target: &TimeZone) -> LocalResult<ZonedClock> {
+        date.and_clock(self.clock)
+            .convert_zone(&self.zone, target)
+            .map(|converted| ZonedClock::new(converted.clock(), target.clone()))
+    }
+}
+
+/// Unlike [`Date::from_ymd`], days past the end of a month roll over into
+/// the next one.
+fn date_from_ymd(year: i32, month: u32, day: u32) -> Date {
+    Date::from_days_since_epoch(days_from_civil(year, month, day))
+}
+
+impl fmt::Display for ZonedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.clock, self.zone)
+    }
+}
+
+/// A TZif v2 file with a single transition into CET in 1970 and the given
+/// footer.
+#[cfg(test)]
+fn tzif_with_footer(footer: &str) -> Vec<u8> {
+    fn header(out: &mut Vec<u8>, version: u8, counts: [u32; 6]) {
+        out.extend_from_slice(b"TZif");
+        out.push(version);
+        out.extend_from_slice(&[0; 15]);
+        for count in counts {
+            out.extend_from_slice(&count.to_be_bytes());
+        }
+    }
+    let mut data = Vec::new();
+    header(&mut data, b'2', [0; 6]);
+    header(&mut data, b'2', [0, 0, 0, 1, 2, 8]);
+    data.extend_from_slice(&0_i64.to_be_bytes());
+    data.push(1);
+    data.extend_from_slice(&3600_i32.to_be_bytes());
+    data.extend_from_slice(&[0, 0]);
+    data.extend_from_slice(&3600_i32.to_be_bytes());
+    data.extend_from_slice(&[0, 4]);
+    data.extend_from_slice(b"LMT\0CET\0");
+    data.push(b'\n');
+    data.extend_from_slice(footer.as_bytes());
+    data.push(b'\n');
+    data
+}
+
+#[cfg(test)]
+pub(crate) fn vienna_like() -> TimeZone {
+    let data = tzif_with_footer("CET-1CEST,M3.5.0,M10.5.0/3");
+    TimeZone::from_tzif("Test/Vienna", &data).unwrap()
+}
+
+#[test]
+fn test_parse_fixed_offsets() {
+    assert_eq!(TimeZone::parse_offset("+02:00").unwrap().name(), "+02:00");
+    assert_eq!(TimeZone::parse_offset("-0530").unwrap().name(), "-05:30");
+    assert_eq!(
+        TimeZone::parse_offset("+09")
+            .unwrap()
+            .local_time_type(0)
+            .utc_offset(),
+        9 * 3600
+    );
+    for invalid in ["02:00", "+2:00", "+24:00", "+01:60", "+0100:", "+01:0"] {
+        assert!(TimeZone::parse_offset(invalid).is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_fixed_offset_conversion() {
+    let clock = ZonedClock::new(Clock::new(1, 30), "+02:00".parse().unwrap());
+    let utc = clock.to_zone_on_date(Date::from_ymd(2024, 6, 1).unwrap(), &TimeZone::utc());
+    assert_eq!(utc.single().unwrap().clock(), Clock::new(23, 30));
+}
+
+#[test]
+fn test_fixed_offset_range() {
+    assert_eq!(TimeZone::fixed(-5 * 3600 - 30).unwrap().name(), "-05:00:30");
+    assert!(TimeZone::fixed(24 * 3600).is_err());
+    assert!(TimeZone::fixed(i32::MIN).is_err());
+    assert!(TimeZone::fixed(i32::MAX).is_err());
+}
+
+#[test]
+fn test_posix_footer_rules() {
+    let tz = vienna_like();
+    let winter = days_from_civil(2024, 1, 15) * SECONDS_PER_DAY;
+    let summer = days_from_civil(2024, 7, 15) * SECONDS_PER_DAY;
+    assert_eq!(tz.local_time_type(winter).abbreviation(), "CET");
+    assert_eq!(tz.local_time_type(summer).abbreviation(), "CEST");
+    assert_eq!(tz.local_time_type(summer).utc_offset(), 7200);
+    assert_eq!(tz.local_time_type(-1).abbreviation(), "LMT");
+    assert_eq!(tz.local_time_type(-1).utc_offset(), 3600);
+}
+
+#[test]
+fn test_ymd_methods_match_date_methods() {
+    let clock = ZonedClock::new(Clock::with_nanos(2, 30, 0, 5), vienna_like());
+    let date = Date::from_ymd(2024, 10, 27).unwrap();
+    assert_eq!(
+        clock.to_zone_on(2024, 10, 27, &TimeZone::utc()),
+        clock.to_zone_on_date(date, &TimeZone::utc())
+    );
+    assert_eq!(clock.offset_on(2024, 10, 27), clock.offset_on_date(date));
+    assert_eq!(
+        clock.to_utc_on(2024, 10, 27),
+        LocalResult::Ambiguous(
+            (date.and_clock(Clock::new(0, 30)).unix_seconds(), 5),
+            (date.and_clock(Clock::new(1, 30)).unix_seconds(), 5)
+        )
+    );
+}
+
+#[test]
+fn test_convert_in_summer() {
+    let noon = ZonedClock::new(Clock::new(12, 0), vienna_like());
+    let utc = noon
+        .to_zone_on_date(Date::from_ymd(2024, 7, 15).unwrap(), &TimeZone::utc())
+        .single()
+        .unwrap();
+    assert_eq!(utc.clock(), Clock::new(10, 0));
+    assert_eq!(utc.to_string(), "10:00 UTC");
+}
+
+#[test]
+fn test_spring_forward_gap() {
+    let clock = ZonedClock::new(Clock::with_seconds(2, 30, 15), vienna_like());
+    let converted = clock.to_zone_on_date(Date::from_ymd(2024, 3, 31).unwrap(), &TimeZone::utc());
+    assert_eq!(
+        converted,
+        LocalResult::Gap(ZonedClock::new(
+            Clock::with_seconds(1, 30, 15),
+            TimeZone::utc()
+        ))
+    );
+    let back = converted
+        .earliest()
+        .to_zone_on_date(Date::from_ymd(2024, 3, 31).unwrap(), &vienna_like());
+    assert_eq!(
+        back.single().unwrap().clock(),
+        Clock::with_seconds(3, 30, 15)
+    );
+}
+
+#[test]
+fn test_fall_back_overlap() {
+    let clock = ZonedClock::new(Clock::new(2, 30), vienna_like());
+    let converted = clock
+        .to_zone_on_date(Date::from_ymd(2024, 10, 27).unwrap(), &TimeZone::utc())
+        .map(|zoned| zoned.clock());
+    assert_eq!(
+        converted,
+        LocalResult::Ambiguous(Clock::new(0, 30), Clock::new(1, 30))
+    );
+    let offsets = clock
+        .offset_on_date(Date::from_ymd(2024, 10, 27).unwrap())
+        .map(|t| t.abbreviation().to_string());
+    assert_eq!(
+        offsets,
+        LocalResult::Ambiguous("CEST".to_string(), "CET".to_string())
+    );
+}
+
+#[test]
+fn test_southern_hemisphere_rule() {
+    let tz = PosixTzParser::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    let january = days_from_civil(2024, 1, 15) * SECONDS_PER_DAY;
+    let july = days_from_civil(2024, 7, 15) * SECONDS_PER_DAY;
+    assert_eq!(tz.local_time_type(january).abbreviation(), "AEDT");
+    assert_eq!(tz.local_time_type(july).abbreviation(), "AEST");
+}
+
+#[test]
+fn test_invalid_tzif() {
+    assert!(matches!(
+        TimeZone::from_tzif("bad", b"TZxf"),
+        Err(TimeZoneError::InvalidTzif(_))
+    ));
+    assert!(matches!(
+        TimeZone::load("../etc/passwd"),
+        Err(TimeZoneError::InvalidName(_))
+    ));
+    assert!(PosixTzParser::parse("CET-1CEST,M3.5.0").is_err());
+    assert!(PosixTzParser::parse("<+0330>-3:30").is_ok());
+}
+
+#[test]
+fn test_posix_time_out_of_range() {
+    for footer in [
+        "EST999999999",
+        "EST168",
+        "EST5:60",
+        "EST5:00:60",
+        "EST5EDT,M3.2.0/999999999,M11.1.0",
+    ] {
+        assert!(
+            matches!(
+                TimeZone::from_tzif("bad", &tzif_with_footer(footer)),
+                Err(TimeZoneError::InvalidPosixTz(_))
+            ),
+            "{}",
+            footer
+        );
+    }
+    assert!(PosixTzParser::parse("EST167:59:59").is_ok());
+    assert!(PosixTzParser::parse("EST5EDT,M3.2.0/-167,M11.1.0").is_ok());
+}